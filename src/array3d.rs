@@ -0,0 +1,492 @@
+//! 3d arrays with the same semantics as 2d arrays.
+
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+
+use mint::Vector3;
+
+use crate::{
+    Array2dError, Array2dMut, Array2dRef,
+    boundary3::{Boundary3, IntoBoundary3},
+    error::checked_usize,
+    storage::{Array2dStorage, Array2dStorageOwned, Array2dStorageSlice, Array2dStorageSliceMut},
+    util::*,
+};
+
+/// A 3d array with generic backing storage.
+///
+/// Items are stored `x` first, then `y`, then `z`,
+/// so each `z` layer is a row major 2d array.
+#[derive(Clone, Copy)]
+pub struct GenericArray3d<S: Array2dStorage> {
+    data: S,
+    boundary: Boundary3,
+    /// Offset between rows.
+    pitch: usize,
+    /// Offset between `z` layers.
+    layer_pitch: usize,
+}
+
+/// A 3d array.
+pub type Array3d<T> = GenericArray3d<Vec<T>>;
+
+/// A readonly view to an existing 3d array.
+pub type Array3dRef<'t, T> = GenericArray3d<&'t [T]>;
+
+/// A mutable view to an existing 3d array.
+pub type Array3dMut<'t, T> = GenericArray3d<&'t mut [T]>;
+
+impl<S: Array2dStorageSlice<Item: Debug>> Debug for GenericArray3d<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GenericArray3d")
+            .field("boundary", &self.boundary)
+            .field("data", &self.data.slice())
+            .field("pitch", &self.pitch)
+            .field("layer_pitch", &self.layer_pitch)
+            .finish()
+    }
+}
+
+impl<T: Default + Array2dStorageOwned> Default for GenericArray3d<T> {
+    fn default() -> Self {
+        GenericArray3d {
+            data: Default::default(),
+            boundary: Boundary3::EMPTY,
+            pitch: 0,
+            layer_pitch: 0,
+        }
+    }
+}
+
+impl<A: Array2dStorageSlice<Item: PartialEq<B::Item>>, B: Array2dStorageSlice>
+    PartialEq<GenericArray3d<B>> for GenericArray3d<A>
+{
+    fn eq(&self, other: &GenericArray3d<B>) -> bool {
+        self.boundary == other.boundary && self.rows().zip(other.rows()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Array2dStorageSlice<Item: Eq>> Eq for GenericArray3d<T> {}
+
+impl<T: Array2dStorageSlice> GenericArray3d<T> {
+    fn offset_of(&self, position: Vector3<i32>) -> usize {
+        // The offset from `min` always fits in a `u32`.
+        let local = |v: i32, min: i32| v.wrapping_sub(min) as u32 as usize;
+        let min = self.boundary.min;
+        local(position.z, min.z) * self.layer_pitch
+            + local(position.y, min.y) * self.pitch
+            + local(position.x, min.x)
+    }
+
+    /// Returns true if contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.boundary.is_empty()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    /// Returns the width of the array.
+    pub fn width(&self) -> usize {
+        self.boundary.dimension.x as usize
+    }
+
+    /// Returns the height of the array.
+    pub fn height(&self) -> usize {
+        self.boundary.dimension.y as usize
+    }
+
+    /// Returns the depth of the array.
+    pub fn depth(&self) -> usize {
+        self.boundary.dimension.z as usize
+    }
+
+    /// Returns the boundary of the array.
+    pub fn boundary(&self) -> Boundary3 {
+        self.boundary
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector3<i32>>) -> bool {
+        self.boundary.contains(position)
+    }
+
+    /// Returns a point.
+    pub fn get(&self, position: impl Into<Vector3<i32>>) -> Option<&T::Item> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        self.data.slice().get(self.offset_of(position))
+    }
+
+    /// Returns continuous slices of rows, `y` first, then `z`.
+    pub fn rows(&self) -> impl Iterator<Item = &[T::Item]> {
+        let slice = self.data.slice();
+        let dimension = self.boundary.dimension;
+        let (pitch, layer_pitch) = (self.pitch, self.layer_pitch);
+        (0..dimension.z as usize).flat_map(move |z| {
+            (0..dimension.y as usize).map(move |y| {
+                let start = z * layer_pitch + y * pitch;
+                &slice[start..start + dimension.x as usize]
+            })
+        })
+    }
+
+    /// Returns all values in the array.
+    pub fn values(&self) -> impl Iterator<Item = &T::Item> {
+        self.rows().flatten()
+    }
+
+    /// Iterate through pairs of points and values in the array.
+    pub fn iter<U: From<Vector3<i32>>>(&self) -> impl Iterator<Item = (U, &T::Item)> {
+        self.boundary.iter::<U>().zip(self.values())
+    }
+
+    /// Obtain a truncated subslice.
+    pub fn slice(&self, boundary: impl IntoBoundary3) -> Array3dRef<'_, T::Item> {
+        match self.boundary.intersection(boundary.into_boundary3()) {
+            Some(intersection) if !intersection.is_empty() => Array3dRef {
+                data: &self.data.slice()[self.offset_of(intersection.min)..],
+                boundary: intersection,
+                pitch: self.pitch,
+                layer_pitch: self.layer_pitch,
+            },
+            _ => Array3dRef {
+                data: &[],
+                boundary: Boundary3::EMPTY,
+                pitch: 0,
+                layer_pitch: 0,
+            },
+        }
+    }
+
+    /// Returns the `xy` plane at `z` as a 2d array.
+    pub fn layer(&self, z: i32) -> Option<Array2dRef<'_, T::Item>> {
+        let b = self.boundary;
+        if z < b.min.z || z > b.max().z || b.is_empty() {
+            return None;
+        }
+        let offset = self.offset_of(Vector3 {
+            x: b.min.x,
+            y: b.min.y,
+            z,
+        });
+        Some(Array2dRef {
+            data: self.data.slice(),
+            boundary: b.plane_xy(),
+            pitch: self.pitch,
+            offset,
+        })
+    }
+
+    /// Returns the `xz` plane at `y` as a 2d array,
+    /// where `z` is mapped to the `y` axis of the 2d array.
+    pub fn layer_xz(&self, y: i32) -> Option<Array2dRef<'_, T::Item>> {
+        let b = self.boundary;
+        if y < b.min.y || y > b.max().y || b.is_empty() {
+            return None;
+        }
+        let offset = self.offset_of(Vector3 {
+            x: b.min.x,
+            y,
+            z: b.min.z,
+        });
+        Some(Array2dRef {
+            data: self.data.slice(),
+            boundary: b.plane_xz(),
+            pitch: self.layer_pitch,
+            offset,
+        })
+    }
+
+    /// Returns all `xy` planes from the smallest `z` to the largest `z`.
+    pub fn layers(&self) -> impl Iterator<Item = Array2dRef<'_, T::Item>> {
+        let min = self.boundary.min.z;
+        (0..self.boundary.dimension.z).filter_map(move |z| self.layer(min + z as i32))
+    }
+
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector3<i32>>) {
+        self.boundary.displace(by);
+    }
+
+    /// Clone the array or slice into an owned array.
+    pub fn cloned(&self) -> Array3d<T::Item>
+    where
+        T::Item: Clone,
+    {
+        let mut data = Vec::with_capacity(self.len());
+        data.extend(self.values().cloned());
+        Array3d::from_vec(data, self.boundary)
+    }
+}
+
+impl<T: Array2dStorageSliceMut> GenericArray3d<T> {
+    /// Returns a point.
+    pub fn get_mut(&mut self, position: impl Into<Vector3<i32>>) -> Option<&mut T::Item> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        let offset = self.offset_of(position);
+        self.data.slice_mut().get_mut(offset)
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, position: impl Into<Vector3<i32>>, value: T::Item) -> bool {
+        if let Some(v) = self.get_mut(position) {
+            *v = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns continuous slices of rows, `y` first, then `z`.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T::Item]> {
+        let dimension = self.boundary.dimension;
+        let (pitch, layer_pitch) = (self.pitch.max(1), self.layer_pitch.max(1));
+        let width = dimension.x as usize;
+        self.data
+            .slice_mut()
+            .chunks_mut(layer_pitch)
+            .take(dimension.z as usize)
+            .flat_map(move |layer| {
+                layer
+                    .chunks_mut(pitch)
+                    .take(dimension.y as usize)
+                    .map(move |row| &mut row[..width])
+            })
+    }
+
+    /// Returns all values in the array.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T::Item> {
+        self.rows_mut().flatten()
+    }
+
+    /// Iterate through pairs of points and values in the array.
+    pub fn iter_mut<U: From<Vector3<i32>>>(&mut self) -> impl Iterator<Item = (U, &mut T::Item)> {
+        self.boundary.iter::<U>().zip(self.values_mut())
+    }
+
+    /// Obtain a truncated subslice.
+    pub fn slice_mut(&mut self, boundary: impl IntoBoundary3) -> Array3dMut<'_, T::Item> {
+        match self.boundary.intersection(boundary.into_boundary3()) {
+            Some(intersection) if !intersection.is_empty() => {
+                let offset = self.offset_of(intersection.min);
+                Array3dMut {
+                    data: &mut self.data.slice_mut()[offset..],
+                    boundary: intersection,
+                    pitch: self.pitch,
+                    layer_pitch: self.layer_pitch,
+                }
+            }
+            _ => Array3dMut {
+                data: &mut [],
+                boundary: Boundary3::EMPTY,
+                pitch: 0,
+                layer_pitch: 0,
+            },
+        }
+    }
+
+    /// Returns the `xy` plane at `z` as a mutable 2d array.
+    pub fn layer_mut(&mut self, z: i32) -> Option<Array2dMut<'_, T::Item>> {
+        let b = self.boundary;
+        if z < b.min.z || z > b.max().z || b.is_empty() {
+            return None;
+        }
+        let offset = self.offset_of(Vector3 {
+            x: b.min.x,
+            y: b.min.y,
+            z,
+        });
+        Some(Array2dMut {
+            data: self.data.slice_mut(),
+            boundary: b.plane_xy(),
+            pitch: self.pitch,
+            offset,
+        })
+    }
+
+    /// Returns the `xz` plane at `y` as a mutable 2d array,
+    /// where `z` is mapped to the `y` axis of the 2d array.
+    pub fn layer_xz_mut(&mut self, y: i32) -> Option<Array2dMut<'_, T::Item>> {
+        let b = self.boundary;
+        if y < b.min.y || y > b.max().y || b.is_empty() {
+            return None;
+        }
+        let offset = self.offset_of(Vector3 {
+            x: b.min.x,
+            y,
+            z: b.min.z,
+        });
+        Some(Array2dMut {
+            data: self.data.slice_mut(),
+            boundary: b.plane_xz(),
+            pitch: self.layer_pitch,
+            offset,
+        })
+    }
+
+    /// Set all values in the array.
+    pub fn fill(&mut self, value: T::Item)
+    where
+        T::Item: Clone,
+    {
+        for item in self.values_mut() {
+            *item = value.clone();
+        }
+    }
+
+    /// Modify a region with another array as a "brush".
+    pub fn paint<U>(
+        &mut self,
+        brush: &GenericArray3d<impl Array2dStorageSlice<Item = U>>,
+        at: impl Into<Vector3<i32>>,
+        mut paint_fn: impl FnMut(&mut T::Item, &U),
+    ) {
+        let region = brush.boundary.displace_by(at);
+        let Some(intersection) = self.boundary.intersection(region) else {
+            return;
+        };
+        let mut target = self.slice_mut(intersection);
+        let source = brush.slice(intersection.displace_by(sub3(brush.boundary.min, region.min)));
+        for (a, b) in target.rows_mut().zip(source.rows()) {
+            for (a, b) in a.iter_mut().zip(b) {
+                paint_fn(a, b)
+            }
+        }
+    }
+}
+
+impl<T: Array2dStorageOwned + Array2dStorageSliceMut> GenericArray3d<T> {
+    /// Initialize an array3d with a function.
+    pub fn init<U: From<Vector3<i32>>>(
+        boundary: impl IntoBoundary3,
+        mut init: impl FnMut(U) -> T::Item,
+    ) -> Self {
+        let boundary = boundary.into_boundary3();
+        let mut vec = Vec::with_capacity(boundary.len());
+        vec.extend(boundary.iter::<Vector3<i32>>().map(|v| init(v.into())));
+        Self::from_vec(vec, boundary)
+    }
+
+    /// Create an `Array3d` with [`Default`] values.
+    pub fn new(boundary: impl IntoBoundary3) -> Self
+    where
+        T::Item: Default,
+    {
+        let boundary = boundary.into_boundary3();
+        let mut vec = Vec::new();
+        vec.resize_with(boundary.len(), Default::default);
+        Self::from_vec(vec, boundary)
+    }
+
+    /// Create an `Array3d` filled with a value.
+    pub fn new_filled(boundary: impl IntoBoundary3, fill: T::Item) -> Self
+    where
+        T::Item: Clone,
+    {
+        let boundary = boundary.into_boundary3();
+        Self::from_vec(vec![fill; boundary.len()], boundary)
+    }
+
+    #[track_caller]
+    /// Create an `Array3d` from a [`Vec`] ordered `x` first, then `y`, then `z`.
+    pub fn from_vec(vec: Vec<T::Item>, boundary: impl IntoBoundary3) -> Self {
+        Self::try_from_vec(vec, boundary).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create an `Array3d` from a [`Vec`] ordered `x` first, then `y`, then `z`,
+    /// returns an error instead of panicking.
+    pub fn try_from_vec(
+        vec: Vec<T::Item>,
+        boundary: impl IntoBoundary3,
+    ) -> Result<Self, Array2dError> {
+        let boundary = boundary.into_boundary3();
+        let pitch = checked_usize::<i32>(boundary.dimension.x)?;
+        let layer_pitch = checked_usize::<i32>(boundary.dimension.y)?
+            .checked_mul(pitch)
+            .ok_or(Array2dError::DimensionOverflow)?;
+        let needed = checked_usize::<i32>(boundary.dimension.z)?
+            .checked_mul(layer_pitch)
+            .ok_or(Array2dError::DimensionOverflow)?;
+        if vec.len() < needed {
+            return Err(Array2dError::NotEnoughItems {
+                needed,
+                got: vec.len(),
+            });
+        }
+        Ok(GenericArray3d {
+            data: T::from_vec(vec),
+            boundary,
+            pitch,
+            layer_pitch,
+        })
+    }
+
+    /// Resize the array and initialize with [`Default::default`].
+    pub fn resize(&mut self, boundary: impl IntoBoundary3)
+    where
+        T::Item: Default,
+    {
+        let boundary = boundary.into_boundary3();
+        if boundary == self.boundary {
+            return;
+        }
+        let mut result = Self::new(boundary);
+        result.paint_owned(self);
+        *self = result;
+    }
+
+    /// Move overlapping items from another array.
+    fn paint_owned(&mut self, other: &mut Self)
+    where
+        T::Item: Default,
+    {
+        let Some(intersection) = self.boundary.intersection(other.boundary) else {
+            return;
+        };
+        let mut source = other.slice_mut(intersection);
+        let mut target = self.slice_mut(intersection);
+        for (a, b) in target.rows_mut().zip(source.rows_mut()) {
+            for (a, b) in a.iter_mut().zip(b) {
+                *a = core::mem::take(b);
+            }
+        }
+    }
+
+    /// Expand the array to include a boundary.
+    pub fn resize_containing(&mut self, boundary: Boundary3)
+    where
+        T::Item: Default,
+    {
+        self.resize(self.boundary.union(boundary));
+    }
+
+    /// Insert a point into an array and potentially expanding the size with [`Default`] values.
+    pub fn insert(&mut self, position: impl Into<Vector3<i32>>, value: T::Item)
+    where
+        T::Item: Default,
+    {
+        let position = position.into();
+        if !self.contains(position) {
+            self.resize_containing(Boundary3::from_point(position));
+        }
+        self.set(position, value);
+    }
+
+    /// Returns the array as a [`Array3dRef`].
+    pub fn as_slice(&self) -> Array3dRef<'_, T::Item> {
+        self.slice(..)
+    }
+
+    /// Returns the array as a [`Array3dMut`].
+    pub fn as_slice_mut(&mut self) -> Array3dMut<'_, T::Item> {
+        self.slice_mut(..)
+    }
+}
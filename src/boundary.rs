@@ -1,146 +1,314 @@
-use std::ops::{
+use core::ops::{
     Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
 };
 
 use mint::Vector2;
 
-use crate::util::*;
+use crate::{coord::Coordinate, util::*};
 
-const MIN: Vector2<i32> = Vector2 {
-    x: i32::MIN,
-    y: i32::MIN,
-};
+/// The smallest allowed point.
+fn min_point<C: Coordinate>() -> Vector2<C> {
+    Vector2 {
+        x: C::MIN,
+        y: C::MIN,
+    }
+}
 
-const MAX: Vector2<i32> = Vector2 {
-    x: i32::MAX - 1,
-    y: i32::MAX - 1,
-};
+/// The largest allowed point, one less than `C::MAX` so `max_non_inclusive` does not overflow.
+fn max_point<C: Coordinate>() -> Vector2<C> {
+    Vector2 {
+        x: C::MAX - C::ONE,
+        y: C::MAX - C::ONE,
+    }
+}
 
-/// Area occupied by a 2d array.
+/// Apply a function to both axis.
+fn map2<T, U>(v: Vector2<T>, mut f: impl FnMut(T) -> U) -> Vector2<U> {
+    Vector2 {
+        x: f(v.x),
+        y: f(v.y),
+    }
+}
+
+/// Area occupied by a 2d array, generic over the coordinate type.
+///
+/// See [`Boundary`] for the default `i32` version.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Boundary {
-    pub min: Vector2<i32>,
-    pub dimension: Vector2<u32>,
+pub struct GenericBoundary<C: Coordinate = i32> {
+    pub min: Vector2<C>,
+    pub dimension: Vector2<C::Unsigned>,
 }
 
-impl Default for Boundary {
+/// Area occupied by a 2d array.
+pub type Boundary = GenericBoundary<i32>;
+
+impl<C: Coordinate> Default for GenericBoundary<C> {
     fn default() -> Self {
-        Self {
-            min: Vector2 { x: 0, y: 0 },
-            dimension: Vector2 { x: 0, y: 0 },
-        }
+        Self::EMPTY
     }
 }
 
-impl<T: Into<Vector2<i32>>> FromIterator<T> for Boundary {
+impl<C: Coordinate, T: Into<Vector2<C>>> FromIterator<T> for GenericBoundary<C> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut min = Vector2 {
-            x: i32::MAX - 1,
-            y: i32::MAX - 1,
-        };
-        let mut max = Vector2 {
-            x: i32::MIN,
-            y: i32::MIN,
-        };
+        let mut min = max_point::<C>();
+        let mut max = min_point::<C>();
         let mut encountered = false;
         for point in iter {
             encountered = true;
-            let point: Vector2<i32> = point.into();
+            let point: Vector2<C> = point.into();
             min = vec_min(min, point);
             max = vec_max(max, point);
         }
         if encountered {
-            Boundary::min_max(min, max)
+            GenericBoundary::min_max(min, max)
         } else {
-            Boundary::EMPTY
+            GenericBoundary::EMPTY
         }
     }
 }
 
-impl Boundary {
+impl<C: Coordinate> GenericBoundary<C> {
     pub const EMPTY: Self = Self {
-        min: Vector2 { x: 0, y: 0 },
-        dimension: Vector2 { x: 0, y: 0 },
+        min: Vector2 {
+            x: C::ZERO,
+            y: C::ZERO,
+        },
+        dimension: Vector2 {
+            x: C::UNSIGNED_ZERO,
+            y: C::UNSIGNED_ZERO,
+        },
     };
 
+    /// Contains every point.
+    ///
+    /// `dimension` can only count `Unsigned::MAX` points per axis,
+    /// [`contains`](GenericBoundary::contains), [`intersection`](GenericBoundary::intersection)
+    /// and [`union`](GenericBoundary::union) treat it as unbounded so it also contains `C::MAX`.
     pub const ALL: Self = Self {
         min: Vector2 {
-            x: i32::MIN,
-            y: i32::MIN,
+            x: C::MIN,
+            y: C::MIN,
         },
         dimension: Vector2 {
-            x: u32::MAX,
-            y: u32::MAX,
+            x: C::UNSIGNED_MAX,
+            y: C::UNSIGNED_MAX,
         },
     };
 
     /// Returns true if contains 0 points.
     pub fn is_empty(&self) -> bool {
-        self.dimension.x == 0 || self.dimension.y == 0
+        self.dimension.x == C::UNSIGNED_ZERO || self.dimension.y == C::UNSIGNED_ZERO
     }
 
-    /// Returns the length of the underlying vector.
+    /// Returns the length of the underlying vector, saturates at `usize::MAX`.
     pub fn len(&self) -> usize {
-        (self.dimension.x * self.dimension.y) as usize
+        self.saturating_len()
+    }
+
+    /// Returns the number of points, saturates at `u64::MAX`.
+    ///
+    /// Never saturates for coordinates of 32 bits or less.
+    pub fn len_u64(&self) -> u64 {
+        u64::try_from(self.len_u128()).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the number of points or `None` if it does not fit in a `usize`.
+    pub fn checked_len(&self) -> Option<usize> {
+        usize::try_from(self.len_u128()).ok()
+    }
+
+    /// Returns the number of points, saturates at `usize::MAX`.
+    pub fn saturating_len(&self) -> usize {
+        usize::try_from(self.len_u128()).unwrap_or(usize::MAX)
+    }
+
+    fn len_u128(&self) -> u128 {
+        let size = |v: C::Unsigned| C::unsigned_to_i128(v) as u128;
+        size(self.dimension.x) * size(self.dimension.y)
     }
 
     /// Returns the major axis.
     pub(crate) fn pitch(&self) -> usize {
-        self.dimension.x as usize
+        C::unsigned_to_usize(self.dimension.x)
+    }
+
+    /// Returns the minor axis.
+    pub(crate) fn height(&self) -> usize {
+        C::unsigned_to_usize(self.dimension.y)
     }
 
     /// Returns the maximum point.
-    pub fn max(&self) -> Vector2<i32> {
-        sub(addu(self.min, self.dimension), [1, 1].into())
+    pub fn max(&self) -> Vector2<C> {
+        map2(addu(self.min, self.dimension), |v| v.wrapping_sub(C::ONE))
     }
 
-    /// Returns `min + dimension` or `max + [1, 1]`.
-    pub fn max_non_inclusive(&self) -> Vector2<i32> {
+    /// Returns `min + dimension` or `max + [1, 1]`, wraps on overflow.
+    pub fn max_non_inclusive(&self) -> Vector2<C> {
         addu(self.min, self.dimension)
     }
 
-    pub fn intersection(&self, other: Boundary) -> Option<Boundary> {
-        let min = vec_max(self.min, other.min);
-        let u1 = self.max_non_inclusive();
-        let u2 = other.max_non_inclusive();
-        let max = vec_min(u1, u2);
+    /// Returns `min + dimension` or `None` on overflow.
+    pub fn checked_max_non_inclusive(&self) -> Option<Vector2<C>> {
+        let max = self.wide().1;
+        (max.x <= C::MAX.to_i128() && max.y <= C::MAX.to_i128()).then(|| map2(max, C::from_i128))
+    }
+
+    /// Returns `min + dimension` clamped to `C::MAX`.
+    pub fn saturating_max_non_inclusive(&self) -> Vector2<C> {
+        map2(self.wide().1, |v| C::from_i128(v.min(C::MAX.to_i128())))
+    }
+
+    /// Returns `min` and `max_non_inclusive` without overflow.
+    pub(crate) fn wide(&self) -> (Vector2<i128>, Vector2<i128>) {
+        let min = map2(self.min, C::to_i128);
+        let max = Vector2 {
+            x: min.x + C::unsigned_to_i128(self.dimension.x),
+            y: min.y + C::unsigned_to_i128(self.dimension.y),
+        };
+        (min, max)
+    }
+
+    /// Like [`wide`](Self::wide), but [`ALL`](Self::ALL) extends to `C::MAX + 1`.
+    fn extent(&self) -> (Vector2<i128>, Vector2<i128>) {
+        if *self == Self::ALL {
+            let (min, max) = (C::MIN.to_i128(), C::MAX.to_i128() + 1);
+            return (Vector2 { x: min, y: min }, Vector2 { x: max, y: max });
+        }
+        self.wide()
+    }
+
+    /// Create from a lossless `min` and `max_non_inclusive`, returns `None` if out of range.
+    fn checked_from_wide(min: Vector2<i128>, max: Vector2<i128>) -> Option<Self> {
+        let (lo, hi) = (C::MIN.to_i128(), C::MAX.to_i128() + 1);
+        if min.x < lo || min.y < lo || max.x > hi || max.y > hi {
+            return None;
+        }
+        Some(Self::saturating_from_wide(min, max))
+    }
+
+    /// Create from a lossless `min` and `max_non_inclusive`,
+    /// `min` is clamped to `C::MIN..=C::MAX` and `max_non_inclusive` to `C::MIN..=C::MAX + 1`.
+    ///
+    /// The full range `C::MIN..=C::MAX` becomes [`ALL`](Self::ALL).
+    pub(crate) fn saturating_from_wide(min: Vector2<i128>, max: Vector2<i128>) -> Self {
+        let (lo, hi) = (C::MIN.to_i128(), C::MAX.to_i128());
+        let min = map2(min, |v| v.clamp(lo, hi));
+        let max = map2(max, |v| v.clamp(lo, hi + 1));
+        let size =
+            |v: i128| C::unsigned_from_i128(v.clamp(0, C::unsigned_to_i128(C::UNSIGNED_MAX)));
+        GenericBoundary {
+            min: map2(min, C::from_i128),
+            dimension: Vector2 {
+                x: size(max.x - min.x),
+                y: size(max.y - min.y),
+            },
+        }
+    }
+
+    /// Returns the intersection of both boundaries or `None` if they do not overlap.
+    ///
+    /// Boundaries overflowing `C::MAX` are clamped, see [`GenericBoundary::saturating_intersection`].
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        self.saturating_intersection(other)
+    }
+
+    /// Returns the intersection of both boundaries,
+    /// returns `None` if they do not overlap or if either boundary overflows `C::MAX`.
+    pub fn checked_intersection(&self, other: Self) -> Option<Self> {
+        let (min, max) = self.wide();
+        Self::checked_from_wide(min, max)?;
+        let (min, max) = other.wide();
+        Self::checked_from_wide(min, max)?;
+        self.saturating_intersection(other)
+    }
+
+    /// Returns the intersection of both boundaries or `None` if they do not overlap,
+    /// boundaries overflowing `C::MAX` are clamped.
+    pub fn saturating_intersection(&self, other: Self) -> Option<Self> {
+        let (min1, max1) = self.extent();
+        let (min2, max2) = other.extent();
+        let min = Vector2 {
+            x: min1.x.max(min2.x),
+            y: min1.y.max(min2.y),
+        };
+        let max = Vector2 {
+            x: max1.x.min(max2.x),
+            y: max1.y.min(max2.y),
+        };
         if max.x < min.x || max.y < min.y {
             None
         } else {
-            Some(Boundary::min_max_non_inclusive(min, max))
+            Some(Self::saturating_from_wide(min, max))
         }
     }
 
+    /// Returns the smallest boundary that contains both boundaries.
+    ///
+    /// Empty boundaries are ignored.
+    pub fn union(&self, other: Self) -> Self {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let (min1, max1) = self.extent();
+        let (min2, max2) = other.extent();
+        let min = Vector2 {
+            x: min1.x.min(min2.x),
+            y: min1.y.min(min2.y),
+        };
+        let max = Vector2 {
+            x: max1.x.max(max2.x),
+            y: max1.y.max(max2.y),
+        };
+        Self::saturating_from_wide(min, max)
+    }
+
     /// Returns boundary of a point with dimension `[1, 1]`.
-    pub fn from_point(point: impl Into<Vector2<i32>>) -> Self {
-        Boundary {
+    pub fn from_point(point: impl Into<Vector2<C>>) -> Self {
+        GenericBoundary {
             min: point.into(),
-            dimension: Vector2 { x: 1, y: 1 },
+            dimension: Vector2 {
+                x: C::UNSIGNED_ONE,
+                y: C::UNSIGNED_ONE,
+            },
         }
     }
 
     /// Returns boundary of a conventional 2d array starting from `[0, 0]`.
-    pub fn from_dimension(dimension: impl Into<Vector2<i32>>) -> Self {
+    pub fn from_dimension(dimension: impl Into<Vector2<C>>) -> Self {
         let dimension = i2u(dimension.into());
-        Boundary {
-            min: Vector2 { x: 0, y: 0 },
+        GenericBoundary {
+            min: Vector2 {
+                x: C::ZERO,
+                y: C::ZERO,
+            },
             dimension,
         }
     }
 
     /// Returns boundary from a minimum and maximum point.
-    pub fn min_max(min: impl Into<Vector2<i32>>, max: impl Into<Vector2<i32>>) -> Self {
+    pub fn min_max(min: impl Into<Vector2<C>>, max: impl Into<Vector2<C>>) -> Self {
         let min = min.into();
         let max = max.into();
-        if min == MIN && max == MAX {
-            // since length is u32::MAX + 1
-            Boundary::ALL
+        if min == min_point() && max == max_point() {
+            // since length is `Unsigned::MAX + 1`
+            Self::ALL
         } else {
             let dimension = Vector2 {
-                x: max.x.wrapping_sub(min.x).wrapping_add(1) as u32,
-                y: max.y.wrapping_sub(min.y).wrapping_add(1) as u32,
+                x: max
+                    .x
+                    .wrapping_sub(min.x)
+                    .wrapping_add(C::ONE)
+                    .cast_unsigned(),
+                y: max
+                    .y
+                    .wrapping_sub(min.y)
+                    .wrapping_add(C::ONE)
+                    .cast_unsigned(),
             };
-            Boundary { min, dimension }
+            GenericBoundary { min, dimension }
         }
     }
 
@@ -148,78 +316,118 @@ impl Boundary {
     ///
     /// # Note
     ///
-    /// `i32::MAX` is not allowed, use `i32::MAX - 1` instead.
+    /// `C::MAX` is not allowed, use `C::MAX - 1` instead.
     pub(crate) fn min_max_non_inclusive(
-        min: impl Into<Vector2<i32>>,
-        max: impl Into<Vector2<i32>>,
+        min: impl Into<Vector2<C>>,
+        max: impl Into<Vector2<C>>,
     ) -> Self {
         let min = min.into();
         let max = max.into();
         let dimension = Vector2 {
-            x: max.x.wrapping_sub(min.x) as u32,
-            y: max.y.wrapping_sub(min.y) as u32,
+            x: max.x.wrapping_sub(min.x).cast_unsigned(),
+            y: max.y.wrapping_sub(min.y).cast_unsigned(),
         };
-        Boundary { min, dimension }
+        GenericBoundary { min, dimension }
     }
 
     /// Returns boundary from a minimum point and a dimension.
-    pub fn min_dim(min: impl Into<Vector2<i32>>, dimension: impl Into<Vector2<i32>>) -> Self {
+    pub fn min_dim(min: impl Into<Vector2<C>>, dimension: impl Into<Vector2<C>>) -> Self {
         let min = min.into();
         let dimension = i2u(abs(dimension.into()));
-        Boundary { min, dimension }
+        GenericBoundary { min, dimension }
     }
 
     /// Returns boundary from a center point and half dimension.
-    pub fn center_hdim(center: impl Into<Vector2<i32>>, half_dim: impl Into<Vector2<i32>>) -> Self {
+    pub fn center_hdim(center: impl Into<Vector2<C>>, half_dim: impl Into<Vector2<C>>) -> Self {
         let center = center.into();
         let half_dim = abs(half_dim.into());
         let min = sub(center, half_dim);
-        let dimension = i2u(add(add(half_dim, half_dim), [1, 1].into()));
-        Boundary { min, dimension }
+        let dimension = i2u(add(add(half_dim, half_dim), one()));
+        GenericBoundary { min, dimension }
     }
 
     /// Returns boundary from 2 ranges.
-    pub fn xy(x: impl RangeBounds<i32>, y: impl RangeBounds<i32>) -> Self {
-        let min_x = match x.start_bound() {
-            Bound::Included(v) => *v,
-            Bound::Excluded(v) => *v + 1,
-            Bound::Unbounded => i32::MIN,
-        };
-        let min_y = match y.start_bound() {
+    pub fn xy(x: impl RangeBounds<C>, y: impl RangeBounds<C>) -> Self {
+        let start = |bound: Bound<&C>| match bound {
             Bound::Included(v) => *v,
-            Bound::Excluded(v) => *v + 1,
-            Bound::Unbounded => i32::MIN,
+            Bound::Excluded(v) => *v + C::ONE,
+            Bound::Unbounded => C::MIN,
         };
-        let max_x = match x.end_bound() {
+        let end = |bound: Bound<&C>| match bound {
             Bound::Included(v) => *v,
-            Bound::Excluded(v) => *v - 1,
-            Bound::Unbounded => i32::MAX - 1,
+            Bound::Excluded(v) => *v - C::ONE,
+            Bound::Unbounded => C::MAX - C::ONE,
         };
-        let max_y = match y.end_bound() {
-            Bound::Included(v) => *v,
-            Bound::Excluded(v) => *v - 1,
-            Bound::Unbounded => i32::MAX - 1,
-        };
-        Boundary::min_max([min_x, min_y], [max_x, max_y])
+        Self::min_max(
+            Vector2 {
+                x: start(x.start_bound()),
+                y: start(y.start_bound()),
+            },
+            Vector2 {
+                x: end(x.end_bound()),
+                y: end(y.end_bound()),
+            },
+        )
     }
 
     /// Move the boundary.
-    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+    ///
+    /// # Panics
+    ///
+    /// If `min` overflows in debug mode.
+    pub fn displace(&mut self, by: impl Into<Vector2<C>>) {
         self.min = add(self.min, by.into())
     }
 
     /// Move the boundary.
-    pub fn displace_by(&self, by: impl Into<Vector2<i32>>) -> Boundary {
+    pub fn displace_by(&self, by: impl Into<Vector2<C>>) -> Self {
         let mut result = *self;
         result.displace(by);
         result
     }
 
+    /// Move the boundary, returns `None` if any point leaves the range of `C`.
+    pub fn checked_displace_by(&self, by: impl Into<Vector2<C>>) -> Option<Self> {
+        let (min, max) = self.displaced_wide(map2(by.into(), C::to_i128));
+        Self::checked_from_wide(min, max)
+    }
+
+    /// Move the boundary, points leaving the range of `C` are clipped.
+    pub fn saturating_displace_by(&self, by: impl Into<Vector2<C>>) -> Self {
+        let (min, max) = self.displaced_wide(map2(by.into(), C::to_i128));
+        Self::saturating_from_wide(min, max)
+    }
+
+    /// Move the boundary by an offset that may not fit in `C`, points leaving the range of `C` are clipped.
+    pub(crate) fn saturating_displace_wide(&self, by: Vector2<i128>) -> Self {
+        let (min, max) = self.displaced_wide(by);
+        Self::saturating_from_wide(min, max)
+    }
+
+    fn displaced_wide(&self, by: Vector2<i128>) -> (Vector2<i128>, Vector2<i128>) {
+        let (min, max) = self.wide();
+        (
+            Vector2 {
+                x: min.x + by.x,
+                y: min.y + by.y,
+            },
+            Vector2 {
+                x: max.x + by.x,
+                y: max.y + by.y,
+            },
+        )
+    }
+
     /// Increase dimension both horizontally and vertically.
     ///
     /// For example expanding `[0, 0]..=[0, 0]` by `[2, 1]`
-    /// results in `[-2, -1]..=[2, 1]`.
-    pub fn expand(&mut self, by: impl Into<Vector2<i32>>) {
+    /// results in `[-2, -1]..=[0, 0]`.
+    ///
+    /// # Panics
+    ///
+    /// On overflow with debug assertions, see [`GenericBoundary::checked_expand_by`]
+    /// and [`GenericBoundary::saturating_expand_by`].
+    pub fn expand(&mut self, by: impl Into<Vector2<C>>) {
         let by = by.into();
         self.min = sub(self.min, by);
         self.dimension = i2u(add(u2i(self.dimension), by));
@@ -228,96 +436,194 @@ impl Boundary {
     /// Increase dimension both horizontally and vertically.
     ///
     /// For example expanding `[0, 0]..=[0, 0]` by `[2, 1]`
-    /// results in `[-2, -1]..=[2, 1]`.
-    pub fn expand_by(&self, by: impl Into<Vector2<i32>>) -> Boundary {
+    /// results in `[-2, -1]..=[0, 0]`.
+    ///
+    /// # Panics
+    ///
+    /// On overflow with debug assertions, see [`GenericBoundary::checked_expand_by`]
+    /// and [`GenericBoundary::saturating_expand_by`].
+    pub fn expand_by(&self, by: impl Into<Vector2<C>>) -> Self {
         let mut result = *self;
         result.expand(by);
         result
     }
 
+    /// Like [`expand_by`](GenericBoundary::expand_by),
+    /// returns `None` if any point leaves the range of `C`.
+    pub fn checked_expand_by(&self, by: impl Into<Vector2<C>>) -> Option<Self> {
+        let (min, max) = self.expanded_wide(by.into());
+        Self::checked_from_wide(min, max)
+    }
+
+    /// Like [`expand_by`](GenericBoundary::expand_by),
+    /// points leaving the range of `C` are clipped.
+    pub fn saturating_expand_by(&self, by: impl Into<Vector2<C>>) -> Self {
+        let (min, max) = self.expanded_wide(by.into());
+        Self::saturating_from_wide(min, max)
+    }
+
+    fn expanded_wide(&self, by: Vector2<C>) -> (Vector2<i128>, Vector2<i128>) {
+        let (min, max) = self.wide();
+        let by = map2(by, C::to_i128);
+        (
+            Vector2 {
+                x: min.x - by.x,
+                y: min.y - by.y,
+            },
+            max,
+        )
+    }
+
+    /// Swap the `x` and `y` axis.
+    pub fn transposed(&self) -> Self {
+        GenericBoundary {
+            min: transpose(self.min),
+            dimension: transpose(self.dimension),
+        }
+    }
+
+    /// Returns a boundary with a new dimension, keeping the anchor point fixed.
+    ///
+    /// For example resizing `[0, 0]..[4, 4]` to `[2, 2]` with [`Anchor::Center`]
+    /// results in `[1, 1]..[3, 3]`.
+    pub fn anchored(&self, dimension: impl Into<Vector2<C>>, anchor: Anchor) -> Self {
+        let dimension = i2u(abs(dimension.into()));
+        let factor = anchor.factor();
+        let size = C::unsigned_to_i128;
+        let dx = (size(self.dimension.x) - size(dimension.x)) * factor.x as i128;
+        let dy = (size(self.dimension.y) - size(dimension.y)) * factor.y as i128;
+        GenericBoundary {
+            min: Vector2 {
+                x: C::from_i128(self.min.x.to_i128() + dx.div_euclid(2)),
+                y: C::from_i128(self.min.y.to_i128() + dy.div_euclid(2)),
+            },
+            dimension,
+        }
+    }
+
     /// Returns `true` if contains a point.
-    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+    pub fn contains(&self, position: impl Into<Vector2<C>>) -> bool {
+        if *self == Self::ALL {
+            return true;
+        }
         let position = position.into();
+        // The offset from `min` always fits in the unsigned counterpart.
+        let local = |v: C, min: C| v.wrapping_sub(min).cast_unsigned();
         position.x >= self.min.x
             && position.y >= self.min.y
-            && position.x < self.min.x.wrapping_add(self.dimension.x as i32)
-            && position.y < self.min.y.wrapping_add(self.dimension.y as i32)
+            && local(position.x, self.min.x) < self.dimension.x
+            && local(position.y, self.min.y) < self.dimension.y
     }
 
     /// Iterate through all points in the boundary.
-    pub fn iter<T: From<Vector2<i32>>>(&self) -> impl Iterator<Item = T> + 'static + use<T> {
+    pub fn iter<T: From<Vector2<C>>>(&self) -> impl Iterator<Item = T> + 'static + use<T, C> {
         let min = self.min;
-        DimensionIter::new(self.dimension).map(move |x| add(x, min).into())
+        DimensionIter::<C>::new(self.dimension).map(move |x| addu(min, x).into())
+    }
+}
+
+/// Point of a [`Boundary`] that stays fixed when resizing with [`Boundary::anchored`].
+///
+/// `Top` refers to the side with the smallest `y` value, which is the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the anchor as multiples of half dimension from `min`.
+    fn factor(&self) -> Vector2<i64> {
+        let (x, y) = match self {
+            Anchor::TopLeft => (0, 0),
+            Anchor::Top => (1, 0),
+            Anchor::TopRight => (2, 0),
+            Anchor::Left => (0, 1),
+            Anchor::Center => (1, 1),
+            Anchor::Right => (2, 1),
+            Anchor::BottomLeft => (0, 2),
+            Anchor::Bottom => (1, 2),
+            Anchor::BottomRight => (2, 2),
+        };
+        Vector2 { x, y }
     }
 }
 
 /// Types that can be used as [`Boundary`].
-pub trait IntoBoundary {
-    fn into_boundary(self) -> Boundary;
+pub trait IntoBoundary<C: Coordinate = i32> {
+    fn into_boundary(self) -> GenericBoundary<C>;
 }
 
-impl IntoBoundary for Boundary {
-    fn into_boundary(self) -> Boundary {
+impl<C: Coordinate> IntoBoundary<C> for GenericBoundary<C> {
+    fn into_boundary(self) -> GenericBoundary<C> {
         self
     }
 }
 
-impl<T: IntoBoundary + Copy> IntoBoundary for &T {
-    fn into_boundary(self) -> Boundary {
-        (*self).into_boundary()
+impl<C: Coordinate> IntoBoundary<C> for &GenericBoundary<C> {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        *self
     }
 }
 
-impl<U: Into<Vector2<i32>>> IntoBoundary for Range<U> {
-    fn into_boundary(self) -> Boundary {
-        Boundary::min_max_non_inclusive(self.start, self.end)
+impl<C: Coordinate, U: Into<Vector2<C>>> IntoBoundary<C> for Range<U> {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary::min_max_non_inclusive(self.start, self.end)
     }
 }
 
-impl<U: Into<Vector2<i32>>> IntoBoundary for RangeInclusive<U> {
-    fn into_boundary(self) -> Boundary {
+impl<C: Coordinate, U: Into<Vector2<C>>> IntoBoundary<C> for RangeInclusive<U> {
+    fn into_boundary(self) -> GenericBoundary<C> {
         let (min, max) = self.into_inner();
-        Boundary::min_max(min, max)
+        GenericBoundary::min_max(min, max)
     }
 }
 
-impl<U: Into<Vector2<i32>>> IntoBoundary for RangeFrom<U> {
-    fn into_boundary(self) -> Boundary {
-        let min = self.start.into();
-        Boundary::min_max(min, MAX)
+impl<C: Coordinate, U: Into<Vector2<C>>> IntoBoundary<C> for RangeFrom<U> {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary::min_max(self.start, max_point())
     }
 }
 
-impl<U: Into<Vector2<i32>>> IntoBoundary for RangeTo<U> {
-    fn into_boundary(self) -> Boundary {
-        let max: Vector2<i32> = self.end.into();
-        Boundary::min_max_non_inclusive(MIN, max)
+impl<C: Coordinate, U: Into<Vector2<C>>> IntoBoundary<C> for RangeTo<U> {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary::min_max_non_inclusive(min_point(), self.end)
     }
 }
 
-impl<U: Into<Vector2<i32>>> IntoBoundary for RangeToInclusive<U> {
-    fn into_boundary(self) -> Boundary {
-        let max: Vector2<i32> = self.end.into();
-        Boundary::min_max(MIN, max)
+impl<C: Coordinate, U: Into<Vector2<C>>> IntoBoundary<C> for RangeToInclusive<U> {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary::min_max(min_point(), self.end)
     }
 }
 
-impl IntoBoundary for RangeFull {
-    fn into_boundary(self) -> Boundary {
-        Boundary::ALL
+impl<C: Coordinate> IntoBoundary<C> for RangeFull {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary::ALL
     }
 }
 
-impl<A: RangeBounds<i32>, B: RangeBounds<i32>> IntoBoundary for (A, B) {
-    fn into_boundary(self) -> Boundary {
-        Boundary::xy(self.0, self.1)
+impl<C: Coordinate, A: RangeBounds<C>, B: RangeBounds<C>> IntoBoundary<C> for (A, B) {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary::xy(self.0, self.1)
     }
 }
 
-impl IntoBoundary for [u32; 2] {
-    fn into_boundary(self) -> Boundary {
-        Boundary {
-            min: Vector2 { x: 0, y: 0 },
+impl<C: Coordinate> IntoBoundary<C> for [C::Unsigned; 2] {
+    fn into_boundary(self) -> GenericBoundary<C> {
+        GenericBoundary {
+            min: Vector2 {
+                x: C::ZERO,
+                y: C::ZERO,
+            },
             dimension: self.into(),
         }
     }
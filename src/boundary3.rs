@@ -0,0 +1,417 @@
+use core::ops::{Bound, Range, RangeBounds, RangeFull, RangeInclusive};
+
+use mint::{Vector2, Vector3};
+
+use crate::{Boundary, util::*};
+
+/// Apply a function to all axis.
+fn map3<T, U>(v: Vector3<T>, mut f: impl FnMut(T) -> U) -> Vector3<U> {
+    Vector3 {
+        x: f(v.x),
+        y: f(v.y),
+        z: f(v.z),
+    }
+}
+
+/// Volume occupied by a 3d array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boundary3 {
+    pub min: Vector3<i32>,
+    pub dimension: Vector3<u32>,
+}
+
+impl Default for Boundary3 {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl Boundary3 {
+    pub const EMPTY: Self = Self {
+        min: Vector3 { x: 0, y: 0, z: 0 },
+        dimension: Vector3 { x: 0, y: 0, z: 0 },
+    };
+
+    /// Contains every point.
+    ///
+    /// `dimension` can only count `u32::MAX` points per axis,
+    /// [`contains`](Boundary3::contains), [`intersection`](Boundary3::intersection)
+    /// and [`union`](Boundary3::union) treat it as unbounded so it also contains `i32::MAX`.
+    pub const ALL: Self = Self {
+        min: Vector3 {
+            x: i32::MIN,
+            y: i32::MIN,
+            z: i32::MIN,
+        },
+        dimension: Vector3 {
+            x: u32::MAX,
+            y: u32::MAX,
+            z: u32::MAX,
+        },
+    };
+
+    /// Returns true if contains 0 points.
+    pub fn is_empty(&self) -> bool {
+        self.dimension.x == 0 || self.dimension.y == 0 || self.dimension.z == 0
+    }
+
+    /// Returns the length of the underlying vector, saturates at `usize::MAX`.
+    pub fn len(&self) -> usize {
+        self.saturating_len()
+    }
+
+    /// Returns the number of points or `None` if it does not fit in a `usize`.
+    pub fn checked_len(&self) -> Option<usize> {
+        usize::try_from(self.len_u128()).ok()
+    }
+
+    /// Returns the number of points, saturates at `usize::MAX`.
+    pub fn saturating_len(&self) -> usize {
+        usize::try_from(self.len_u128()).unwrap_or(usize::MAX)
+    }
+
+    fn len_u128(&self) -> u128 {
+        self.dimension.x as u128 * self.dimension.y as u128 * self.dimension.z as u128
+    }
+
+    /// Returns the maximum point.
+    pub fn max(&self) -> Vector3<i32> {
+        let max = self.max_non_inclusive();
+        Vector3 {
+            x: max.x.wrapping_sub(1),
+            y: max.y.wrapping_sub(1),
+            z: max.z.wrapping_sub(1),
+        }
+    }
+
+    /// Returns `min + dimension` or `max + [1, 1, 1]`, wraps on overflow.
+    pub fn max_non_inclusive(&self) -> Vector3<i32> {
+        addu3(self.min, self.dimension)
+    }
+
+    /// Returns `min + dimension` or `None` on overflow.
+    pub fn checked_max_non_inclusive(&self) -> Option<Vector3<i32>> {
+        let max = self.wide().1;
+        Some(Vector3 {
+            x: i32::try_from(max.x).ok()?,
+            y: i32::try_from(max.y).ok()?,
+            z: i32::try_from(max.z).ok()?,
+        })
+    }
+
+    /// Returns `min + dimension` clamped to `i32::MAX`.
+    pub fn saturating_max_non_inclusive(&self) -> Vector3<i32> {
+        map3(self.wide().1, |v| v.min(i32::MAX as i64) as i32)
+    }
+
+    /// Returns `min` and `max_non_inclusive` without overflow.
+    fn wide(&self) -> (Vector3<i64>, Vector3<i64>) {
+        let min = map3(self.min, i64::from);
+        let max = Vector3 {
+            x: min.x + self.dimension.x as i64,
+            y: min.y + self.dimension.y as i64,
+            z: min.z + self.dimension.z as i64,
+        };
+        (min, max)
+    }
+
+    /// Like [`wide`](Self::wide), but [`ALL`](Self::ALL) extends to `i32::MAX + 1`.
+    fn extent(&self) -> (Vector3<i64>, Vector3<i64>) {
+        if *self == Self::ALL {
+            let (min, max) = (i32::MIN as i64, i32::MAX as i64 + 1);
+            return (
+                Vector3 {
+                    x: min,
+                    y: min,
+                    z: min,
+                },
+                Vector3 {
+                    x: max,
+                    y: max,
+                    z: max,
+                },
+            );
+        }
+        self.wide()
+    }
+
+    /// Create from a lossless `min` and `max_non_inclusive`,
+    /// `min` is clamped to `i32::MIN..=i32::MAX` and `max_non_inclusive` to `i32::MIN..=i32::MAX + 1`.
+    fn saturating_from_wide(min: Vector3<i64>, max: Vector3<i64>) -> Self {
+        let (lo, hi) = (i32::MIN as i64, i32::MAX as i64);
+        let min = map3(min, |v| v.clamp(lo, hi));
+        let max = map3(max, |v| v.clamp(lo, hi + 1));
+        let size = |v: i64| v.clamp(0, u32::MAX as i64) as u32;
+        Boundary3 {
+            min: map3(min, |v| v as i32),
+            dimension: Vector3 {
+                x: size(max.x - min.x),
+                y: size(max.y - min.y),
+                z: size(max.z - min.z),
+            },
+        }
+    }
+
+    /// Returns the 2d boundary on the `xy` plane.
+    pub fn plane_xy(&self) -> Boundary {
+        Boundary {
+            min: Vector2 {
+                x: self.min.x,
+                y: self.min.y,
+            },
+            dimension: Vector2 {
+                x: self.dimension.x,
+                y: self.dimension.y,
+            },
+        }
+    }
+
+    /// Returns the 2d boundary on the `xz` plane.
+    pub fn plane_xz(&self) -> Boundary {
+        Boundary {
+            min: Vector2 {
+                x: self.min.x,
+                y: self.min.z,
+            },
+            dimension: Vector2 {
+                x: self.dimension.x,
+                y: self.dimension.z,
+            },
+        }
+    }
+
+    /// Returns the intersection of both boundaries or `None` if they do not overlap.
+    ///
+    /// Boundaries overflowing `i32::MAX` are clamped, see [`Boundary3::saturating_intersection`].
+    pub fn intersection(&self, other: Boundary3) -> Option<Boundary3> {
+        self.saturating_intersection(other)
+    }
+
+    /// Returns the intersection of both boundaries,
+    /// returns `None` if they do not overlap or if either boundary overflows `i32::MAX`.
+    pub fn checked_intersection(&self, other: Boundary3) -> Option<Boundary3> {
+        let limit = i32::MAX as i64 + 1;
+        for boundary in [self, &other] {
+            let max = boundary.wide().1;
+            if max.x > limit || max.y > limit || max.z > limit {
+                return None;
+            }
+        }
+        self.saturating_intersection(other)
+    }
+
+    /// Returns the intersection of both boundaries or `None` if they do not overlap,
+    /// boundaries overflowing `i32::MAX` are clamped.
+    pub fn saturating_intersection(&self, other: Boundary3) -> Option<Boundary3> {
+        let (min1, max1) = self.extent();
+        let (min2, max2) = other.extent();
+        let min = Vector3 {
+            x: min1.x.max(min2.x),
+            y: min1.y.max(min2.y),
+            z: min1.z.max(min2.z),
+        };
+        let max = Vector3 {
+            x: max1.x.min(max2.x),
+            y: max1.y.min(max2.y),
+            z: max1.z.min(max2.z),
+        };
+        if max.x < min.x || max.y < min.y || max.z < min.z {
+            None
+        } else {
+            Some(Boundary3::saturating_from_wide(min, max))
+        }
+    }
+
+    /// Returns the smallest boundary that contains both boundaries.
+    ///
+    /// Empty boundaries are ignored.
+    pub fn union(&self, other: Boundary3) -> Boundary3 {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let (min1, max1) = self.extent();
+        let (min2, max2) = other.extent();
+        let min = Vector3 {
+            x: min1.x.min(min2.x),
+            y: min1.y.min(min2.y),
+            z: min1.z.min(min2.z),
+        };
+        let max = Vector3 {
+            x: max1.x.max(max2.x),
+            y: max1.y.max(max2.y),
+            z: max1.z.max(max2.z),
+        };
+        Boundary3::saturating_from_wide(min, max)
+    }
+
+    /// Returns boundary of a point with dimension `[1, 1, 1]`.
+    pub fn from_point(point: impl Into<Vector3<i32>>) -> Self {
+        Boundary3 {
+            min: point.into(),
+            dimension: Vector3 { x: 1, y: 1, z: 1 },
+        }
+    }
+
+    /// Returns boundary from a minimum and maximum point.
+    pub fn min_max(min: impl Into<Vector3<i32>>, max: impl Into<Vector3<i32>>) -> Self {
+        let min = min.into();
+        let max = max.into();
+        let dimension = Vector3 {
+            x: max.x.wrapping_sub(min.x).wrapping_add(1) as u32,
+            y: max.y.wrapping_sub(min.y).wrapping_add(1) as u32,
+            z: max.z.wrapping_sub(min.z).wrapping_add(1) as u32,
+        };
+        Boundary3 { min, dimension }
+    }
+
+    /// Returns boundary from a minimum and a non-inclusive maximum point.
+    pub(crate) fn min_max_non_inclusive(
+        min: impl Into<Vector3<i32>>,
+        max: impl Into<Vector3<i32>>,
+    ) -> Self {
+        let min = min.into();
+        let max = max.into();
+        let dimension = Vector3 {
+            x: max.x.wrapping_sub(min.x) as u32,
+            y: max.y.wrapping_sub(min.y) as u32,
+            z: max.z.wrapping_sub(min.z) as u32,
+        };
+        Boundary3 { min, dimension }
+    }
+
+    /// Returns boundary from a minimum point and a dimension.
+    pub fn min_dim(min: impl Into<Vector3<i32>>, dimension: impl Into<Vector3<u32>>) -> Self {
+        Boundary3 {
+            min: min.into(),
+            dimension: dimension.into(),
+        }
+    }
+
+    /// Returns boundary from 3 ranges.
+    pub fn xyz(
+        x: impl RangeBounds<i32>,
+        y: impl RangeBounds<i32>,
+        z: impl RangeBounds<i32>,
+    ) -> Self {
+        fn bounds(range: impl RangeBounds<i32>) -> (i32, i32) {
+            let min = match range.start_bound() {
+                Bound::Included(v) => *v,
+                Bound::Excluded(v) => *v + 1,
+                Bound::Unbounded => i32::MIN,
+            };
+            let max = match range.end_bound() {
+                Bound::Included(v) => *v,
+                Bound::Excluded(v) => *v - 1,
+                Bound::Unbounded => i32::MAX - 1,
+            };
+            (min, max)
+        }
+        let (min_x, max_x) = bounds(x);
+        let (min_y, max_y) = bounds(y);
+        let (min_z, max_z) = bounds(z);
+        Boundary3::min_max([min_x, min_y, min_z], [max_x, max_y, max_z])
+    }
+
+    /// Move the boundary.
+    pub fn displace(&mut self, by: impl Into<Vector3<i32>>) {
+        self.min = add3(self.min, by.into())
+    }
+
+    /// Move the boundary.
+    pub fn displace_by(&self, by: impl Into<Vector3<i32>>) -> Boundary3 {
+        let mut result = *self;
+        result.displace(by);
+        result
+    }
+
+    /// Returns `true` if contains a point.
+    pub fn contains(&self, position: impl Into<Vector3<i32>>) -> bool {
+        if *self == Self::ALL {
+            return true;
+        }
+        let position = position.into();
+        // The offset from `min` always fits in a `u32`.
+        let local = |v: i32, min: i32| v.wrapping_sub(min) as u32;
+        position.x >= self.min.x
+            && position.y >= self.min.y
+            && position.z >= self.min.z
+            && local(position.x, self.min.x) < self.dimension.x
+            && local(position.y, self.min.y) < self.dimension.y
+            && local(position.z, self.min.z) < self.dimension.z
+    }
+
+    /// Iterate through all points in the boundary, `x` first, then `y`, then `z`.
+    pub fn iter<T: From<Vector3<i32>>>(&self) -> impl Iterator<Item = T> + 'static + use<T> {
+        let min = self.min;
+        let dimension = self.dimension;
+        (0..dimension.z).flat_map(move |z| {
+            (0..dimension.y).flat_map(move |y| {
+                (0..dimension.x).map(move |x| {
+                    add3(
+                        min,
+                        Vector3 {
+                            x: x as i32,
+                            y: y as i32,
+                            z: z as i32,
+                        },
+                    )
+                    .into()
+                })
+            })
+        })
+    }
+}
+
+/// Types that can be used as [`Boundary3`].
+pub trait IntoBoundary3 {
+    fn into_boundary3(self) -> Boundary3;
+}
+
+impl IntoBoundary3 for Boundary3 {
+    fn into_boundary3(self) -> Boundary3 {
+        self
+    }
+}
+
+impl<T: IntoBoundary3 + Copy> IntoBoundary3 for &T {
+    fn into_boundary3(self) -> Boundary3 {
+        (*self).into_boundary3()
+    }
+}
+
+impl<U: Into<Vector3<i32>>> IntoBoundary3 for Range<U> {
+    fn into_boundary3(self) -> Boundary3 {
+        Boundary3::min_max_non_inclusive(self.start, self.end)
+    }
+}
+
+impl<U: Into<Vector3<i32>>> IntoBoundary3 for RangeInclusive<U> {
+    fn into_boundary3(self) -> Boundary3 {
+        let (min, max) = self.into_inner();
+        Boundary3::min_max(min, max)
+    }
+}
+
+impl IntoBoundary3 for RangeFull {
+    fn into_boundary3(self) -> Boundary3 {
+        Boundary3::ALL
+    }
+}
+
+impl<A: RangeBounds<i32>, B: RangeBounds<i32>, C: RangeBounds<i32>> IntoBoundary3 for (A, B, C) {
+    fn into_boundary3(self) -> Boundary3 {
+        Boundary3::xyz(self.0, self.1, self.2)
+    }
+}
+
+impl IntoBoundary3 for [u32; 3] {
+    fn into_boundary3(self) -> Boundary3 {
+        Boundary3 {
+            min: Vector3 { x: 0, y: 0, z: 0 },
+            dimension: self.into(),
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Column major arrays.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use mint::Vector2;
+
+use crate::{
+    Array2d, Array2dError, Boundary, GenericArray2d,
+    boundary::IntoBoundary,
+    storage::{
+        Array2dStorage, Array2dStorageMut, Array2dStorageOwned, Array2dStorageRef,
+        Array2dStorageSlice, Array2dStorageSliceMut,
+    },
+    util::*,
+};
+
+/// A column major 2d array with generic backing storage.
+///
+/// Items are stored `y` first, then `x`, columns are continuous slices.
+///
+/// Internally a row major array with the `x` and `y` axis swapped.
+#[derive(Clone, Copy)]
+pub struct GenericColumnMajorArray2d<S: Array2dStorage> {
+    transposed: GenericArray2d<S>,
+}
+
+impl<S: Array2dStorageRef<Item: Debug>> Debug for GenericColumnMajorArray2d<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GenericColumnMajorArray2d")
+            .field("boundary", &self.boundary())
+            .field("data", &self.values().collect::<Vec<_>>())
+            .field("pitch", &self.transposed.pitch)
+            .finish()
+    }
+}
+
+/// A column major 2d array.
+pub type ColumnMajorArray2d<T> = GenericColumnMajorArray2d<Vec<T>>;
+
+/// A readonly column major 2d array backed by a slice or a view to an existing array.
+pub type ColumnMajorArray2dRef<'t, T> = GenericColumnMajorArray2d<&'t [T]>;
+
+/// A mutable column major 2d array backed by a slice or a view to an existing array.
+pub type ColumnMajorArray2dMut<'t, T> = GenericColumnMajorArray2d<&'t mut [T]>;
+
+impl<T: Default + Array2dStorageOwned> Default for GenericColumnMajorArray2d<T> {
+    fn default() -> Self {
+        GenericColumnMajorArray2d {
+            transposed: Default::default(),
+        }
+    }
+}
+
+impl<S: Array2dStorageRef> IntoBoundary for &GenericColumnMajorArray2d<S> {
+    fn into_boundary(self) -> Boundary {
+        self.boundary()
+    }
+}
+
+impl<T: Array2dStorageRef> GenericColumnMajorArray2d<T> {
+    /// Returns true if contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.transposed.is_empty()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> usize {
+        self.transposed.len()
+    }
+
+    /// Returns the width of the array.
+    pub fn width(&self) -> usize {
+        self.transposed.height()
+    }
+
+    /// Returns the height of the array.
+    pub fn height(&self) -> usize {
+        self.transposed.width()
+    }
+
+    /// Returns the offset between the starts of each column.
+    pub fn pitch(&self) -> usize {
+        self.transposed.pitch()
+    }
+
+    /// Returns the boundary of the array.
+    pub fn boundary(&self) -> Boundary {
+        self.transposed.boundary().transposed()
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.transposed.contains(transpose(position.into()))
+    }
+
+    /// Returns a point.
+    pub fn get(&self, position: impl Into<Vector2<i32>>) -> Option<&T::Item> {
+        self.transposed.get(transpose(position.into()))
+    }
+
+    /// Iterate through pairs of points and values in the array, in storage order.
+    pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, &T::Item)> {
+        self.transposed
+            .iter::<Vector2<i32>>()
+            .map(|(p, v)| (transpose(p).into(), v))
+    }
+
+    /// Returns strided iterators defined by the minor axis.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T::Item>> {
+        self.transposed.columns()
+    }
+
+    /// Returns all values in the array, in storage order.
+    pub fn values(&self) -> impl Iterator<Item = &T::Item> {
+        self.transposed.values()
+    }
+
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+        self.transposed.displace(transpose(by.into()));
+    }
+
+    /// Returns the underlying row major array with the `x` and `y` axis swapped.
+    pub fn as_transposed(&self) -> &GenericArray2d<T> {
+        &self.transposed
+    }
+
+    /// Convert into the underlying row major array with the `x` and `y` axis swapped.
+    pub fn into_transposed(self) -> GenericArray2d<T> {
+        self.transposed
+    }
+
+    /// Clone into a row major [`Array2d`].
+    pub fn to_row_major(&self) -> Array2d<T::Item>
+    where
+        T::Item: Clone,
+    {
+        Array2d::init(self.boundary(), |p: Vector2<i32>| {
+            self.get(p).expect("Must be in boundary.").clone()
+        })
+    }
+}
+
+impl<T: Array2dStorageMut> GenericColumnMajorArray2d<T> {
+    /// Returns a point.
+    pub fn get_mut(&mut self, position: impl Into<Vector2<i32>>) -> Option<&mut T::Item> {
+        self.transposed.get_mut(transpose(position.into()))
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, position: impl Into<Vector2<i32>>, value: T::Item) -> bool {
+        self.transposed.set(transpose(position.into()), value)
+    }
+
+    /// Iterate through pairs of points and values in the array, in storage order.
+    pub fn iter_mut<U: From<Vector2<i32>>>(&mut self) -> impl Iterator<Item = (U, &mut T::Item)> {
+        self.transposed
+            .iter_mut::<Vector2<i32>>()
+            .map(|(p, v)| (transpose(p).into(), v))
+    }
+
+    /// Returns all values in the array, in storage order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T::Item> {
+        self.transposed.values_mut()
+    }
+
+    /// Set all values in the array.
+    pub fn fill(&mut self, value: T::Item)
+    where
+        T::Item: Clone,
+    {
+        self.transposed.fill(value);
+    }
+
+    /// Modify a region with another column major array as a "brush".
+    pub fn paint<U>(
+        &mut self,
+        brush: &GenericColumnMajorArray2d<impl Array2dStorageRef<Item = U>>,
+        at: impl Into<Vector2<i32>>,
+        paint_fn: impl FnMut(&mut T::Item, &U),
+    ) {
+        self.transposed
+            .paint(&brush.transposed, transpose(at.into()), paint_fn);
+    }
+}
+
+impl<T: Array2dStorageSlice> GenericColumnMajorArray2d<T> {
+    /// Returns continuous slices defined by the major axis.
+    pub fn columns(&self) -> impl Iterator<Item = &[T::Item]> {
+        self.transposed.rows()
+    }
+
+    /// Obtain a truncated subslice.
+    pub fn slice(&self, boundary: impl IntoBoundary) -> ColumnMajorArray2dRef<'_, T::Item> {
+        GenericColumnMajorArray2d {
+            transposed: self.transposed.slice(boundary.into_boundary().transposed()),
+        }
+    }
+}
+
+impl<T: Array2dStorageSliceMut> GenericColumnMajorArray2d<T> {
+    /// Returns continuous slices defined by the major axis.
+    pub fn columns_mut(&mut self) -> impl Iterator<Item = &mut [T::Item]> {
+        self.transposed.rows_mut()
+    }
+
+    /// Obtain a truncated subslice.
+    pub fn slice_mut(&mut self, boundary: impl IntoBoundary) -> ColumnMajorArray2dMut<'_, T::Item> {
+        GenericColumnMajorArray2d {
+            transposed: self
+                .transposed
+                .slice_mut(boundary.into_boundary().transposed()),
+        }
+    }
+}
+
+impl<T: Array2dStorageOwned> GenericColumnMajorArray2d<T> {
+    /// Initialize a column major array with a function.
+    pub fn init<U: From<Vector2<i32>>>(
+        boundary: impl IntoBoundary,
+        mut init: impl FnMut(U) -> T::Item,
+    ) -> Self {
+        GenericColumnMajorArray2d {
+            transposed: GenericArray2d::init(
+                boundary.into_boundary().transposed(),
+                |p: Vector2<i32>| init(transpose(p).into()),
+            ),
+        }
+    }
+
+    /// Create a column major array with [`Default`] values.
+    pub fn new(boundary: impl IntoBoundary) -> Self
+    where
+        T::Item: Default,
+    {
+        GenericColumnMajorArray2d {
+            transposed: GenericArray2d::new(boundary.into_boundary().transposed()),
+        }
+    }
+
+    #[track_caller]
+    /// Create a column major array from a column major [`Vec`].
+    ///
+    /// # Panics
+    ///
+    /// If the vec has less items than the boundary.
+    pub fn from_vec(vec: Vec<T::Item>, boundary: impl IntoBoundary) -> Self {
+        Self::try_from_vec(vec, boundary).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a column major array from a column major [`Vec`],
+    /// returns an error instead of panicking.
+    pub fn try_from_vec(
+        vec: Vec<T::Item>,
+        boundary: impl IntoBoundary,
+    ) -> Result<Self, Array2dError> {
+        Ok(GenericColumnMajorArray2d {
+            transposed: GenericArray2d::try_from_vec(vec, boundary.into_boundary().transposed())?,
+        })
+    }
+
+    /// Create a column major array from a row major array.
+    pub fn from_row_major(array: GenericArray2d<impl Array2dStorageOwned<Item = T::Item>>) -> Self {
+        let boundary = array.boundary();
+        let (width, height) = (array.width(), array.height());
+        let mut items: Vec<_> = array
+            .iter_owned::<Vector2<i32>>()
+            .map(|(_, v)| Some(v))
+            .collect();
+        let mut vec = Vec::with_capacity(items.len());
+        for x in 0..width {
+            for y in 0..height {
+                vec.extend(items[y * width + x].take());
+            }
+        }
+        Self::from_vec(vec, boundary)
+    }
+
+    /// Convert into a row major [`Array2d`].
+    pub fn into_row_major(self) -> Array2d<T::Item> {
+        let boundary = self.boundary();
+        let transposed = self.transposed;
+        let (width, height) = (transposed.height(), transposed.width());
+        let mut items: Vec<_> = transposed
+            .iter_owned::<Vector2<i32>>()
+            .map(|(_, v)| Some(v))
+            .collect();
+        let mut vec = Vec::with_capacity(items.len());
+        for y in 0..height {
+            for x in 0..width {
+                vec.extend(items[x * height + y].take());
+            }
+        }
+        Array2d::from_vec(vec, boundary)
+    }
+}
+
+impl<T: Array2dStorageRef> GenericArray2d<T> {
+    /// Clone into a [`ColumnMajorArray2d`].
+    pub fn to_column_major(&self) -> ColumnMajorArray2d<T::Item>
+    where
+        T::Item: Clone,
+    {
+        ColumnMajorArray2d::init(self.boundary(), |p: Vector2<i32>| {
+            self.get(p).expect("Must be in boundary.").clone()
+        })
+    }
+}
+
+impl<T> From<Array2d<T>> for ColumnMajorArray2d<T> {
+    fn from(value: Array2d<T>) -> Self {
+        ColumnMajorArray2d::from_row_major(value)
+    }
+}
+
+impl<T> From<ColumnMajorArray2d<T>> for Array2d<T> {
+    fn from(value: ColumnMajorArray2d<T>) -> Self {
+        value.into_row_major()
+    }
+}
@@ -0,0 +1,143 @@
+//! Integer types usable as coordinates.
+
+use core::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A signed integer type usable as the coordinate of [`GenericBoundary`](crate::GenericBoundary)
+/// and [`GenericArray2d`](crate::GenericArray2d).
+///
+/// Implemented on `i8`, `i16`, `i32`, `i64` and `isize`, dimensions use the unsigned counterpart.
+pub trait Coordinate:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Default
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The unsigned counterpart used for dimensions.
+    type Unsigned: Copy
+        + Ord
+        + Hash
+        + Debug
+        + Default
+        + Send
+        + Sync
+        + 'static
+        + Add<Output = Self::Unsigned>
+        + Sub<Output = Self::Unsigned>
+        + Mul<Output = Self::Unsigned>;
+
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const UNSIGNED_ZERO: Self::Unsigned;
+    const UNSIGNED_ONE: Self::Unsigned;
+    const UNSIGNED_MAX: Self::Unsigned;
+
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+    fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+    fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+    fn abs(self) -> Self;
+    /// Reinterpret as unsigned, equivalent to `as`.
+    fn cast_unsigned(self) -> Self::Unsigned;
+    /// Reinterpret an unsigned value as signed, equivalent to `as`.
+    fn cast_signed(value: Self::Unsigned) -> Self;
+    /// Equivalent to `value as usize`.
+    fn unsigned_to_usize(value: Self::Unsigned) -> usize;
+    /// Equivalent to `value as Self::Unsigned`.
+    fn unsigned_from_usize(value: usize) -> Self::Unsigned;
+    /// Lossless conversion used for intermediate calculations.
+    fn to_i128(self) -> i128;
+    /// Equivalent to `value as Self`.
+    fn from_i128(value: i128) -> Self;
+    /// Lossless conversion used for intermediate calculations.
+    fn unsigned_to_i128(value: Self::Unsigned) -> i128;
+    /// Equivalent to `value as Self::Unsigned`.
+    fn unsigned_from_i128(value: i128) -> Self::Unsigned;
+}
+
+macro_rules! impl_coordinate {
+    ($($ty: ident $unsigned: ident),*) => {$(
+        impl Coordinate for $ty {
+            type Unsigned = $unsigned;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = $ty::MIN;
+            const MAX: Self = $ty::MAX;
+            const UNSIGNED_ZERO: Self::Unsigned = 0;
+            const UNSIGNED_ONE: Self::Unsigned = 1;
+            const UNSIGNED_MAX: Self::Unsigned = $unsigned::MAX;
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                $ty::wrapping_add(self, rhs)
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                $ty::wrapping_sub(self, rhs)
+            }
+
+            fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self {
+                $ty::wrapping_add_unsigned(self, rhs)
+            }
+
+            fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self {
+                $ty::saturating_add_unsigned(self, rhs)
+            }
+
+            fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self {
+                $ty::saturating_sub_unsigned(self, rhs)
+            }
+
+            fn abs(self) -> Self {
+                $ty::abs(self)
+            }
+
+            fn cast_unsigned(self) -> Self::Unsigned {
+                self as $unsigned
+            }
+
+            fn cast_signed(value: Self::Unsigned) -> Self {
+                value as $ty
+            }
+
+            fn unsigned_to_usize(value: Self::Unsigned) -> usize {
+                value as usize
+            }
+
+            fn unsigned_from_usize(value: usize) -> Self::Unsigned {
+                value as $unsigned
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as $ty
+            }
+
+            fn unsigned_to_i128(value: Self::Unsigned) -> i128 {
+                value as i128
+            }
+
+            fn unsigned_from_i128(value: i128) -> Self::Unsigned {
+                value as $unsigned
+            }
+        }
+    )*};
+}
+
+impl_coordinate!(i8 u8, i16 u16, i32 u32, i64 u64, isize usize);
@@ -0,0 +1,133 @@
+//! `HashMap` like entry API for growable arrays.
+
+use mint::Vector2;
+
+use crate::{Coordinate, GenericArray2d, GenericBoundary, storage::Array2dStorageOwned};
+
+/// A view into a single point of an array, which may be in or out of the boundary.
+///
+/// Created by [`GenericArray2d::entry`].
+pub enum Entry<'t, T: Array2dStorageOwned<Item: Default>, C: Coordinate = i32> {
+    Occupied(OccupiedEntry<'t, T, C>),
+    Vacant(VacantEntry<'t, T, C>),
+}
+
+/// A point inside the boundary of the array.
+pub struct OccupiedEntry<'t, T: Array2dStorageOwned<Item: Default>, C: Coordinate = i32> {
+    position: Vector2<C>,
+    item: &'t mut T::Item,
+}
+
+/// A point outside the boundary of the array,
+/// inserting will grow the array to contain the point.
+pub struct VacantEntry<'t, T: Array2dStorageOwned<Item: Default>, C: Coordinate = i32> {
+    position: Vector2<C>,
+    array: &'t mut GenericArray2d<T, C>,
+}
+
+impl<'t, T: Array2dStorageOwned<Item: Default>, C: Coordinate> Entry<'t, T, C> {
+    /// Returns the position of the entry.
+    pub fn position<U: From<Vector2<C>>>(&self) -> U {
+        match self {
+            Entry::Occupied(entry) => entry.position(),
+            Entry::Vacant(entry) => entry.position(),
+        }
+    }
+
+    /// Insert a value if out of boundary, then returns a mutable reference to the value.
+    pub fn or_insert(self, value: T::Item) -> &'t mut T::Item {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(value),
+        }
+    }
+
+    /// Insert a value with a function if out of boundary, then returns a mutable reference to the value.
+    pub fn or_insert_with(self, f: impl FnOnce() -> T::Item) -> &'t mut T::Item {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Insert [`Default::default`] if out of boundary, then returns a mutable reference to the value.
+    pub fn or_default(self) -> &'t mut T::Item {
+        self.or_insert_with(Default::default)
+    }
+
+    /// Modify the value if in boundary.
+    pub fn and_modify(self, f: impl FnOnce(&mut T::Item)) -> Self {
+        match self {
+            Entry::Occupied(entry) => {
+                f(entry.item);
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'t, T: Array2dStorageOwned<Item: Default>, C: Coordinate> OccupiedEntry<'t, T, C> {
+    /// Returns the position of the entry.
+    pub fn position<U: From<Vector2<C>>>(&self) -> U {
+        self.position.into()
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &T::Item {
+        self.item
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut T::Item {
+        self.item
+    }
+
+    /// Converts into a mutable reference to the value.
+    pub fn into_mut(self) -> &'t mut T::Item {
+        self.item
+    }
+
+    /// Replace the value and returns the old value.
+    pub fn insert(&mut self, value: T::Item) -> T::Item {
+        core::mem::replace(self.item, value)
+    }
+}
+
+impl<'t, T: Array2dStorageOwned<Item: Default>, C: Coordinate> VacantEntry<'t, T, C> {
+    /// Returns the position of the entry.
+    pub fn position<U: From<Vector2<C>>>(&self) -> U {
+        self.position.into()
+    }
+
+    /// Grow the array to contain the position and insert a value.
+    pub fn insert(self, value: T::Item) -> &'t mut T::Item {
+        self.array
+            .resize_containing(GenericBoundary::from_point(self.position));
+        let item = self
+            .array
+            .get_mut(self.position)
+            .expect("Point must be in boundary after resizing.");
+        *item = value;
+        item
+    }
+}
+
+impl<T: Array2dStorageOwned<Item: Default>, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the [`Entry`] of a point for in-place manipulation,
+    /// inserting into a [`Entry::Vacant`] grows the array.
+    pub fn entry(&mut self, position: impl Into<Vector2<C>>) -> Entry<'_, T, C> {
+        let position = position.into();
+        if self.contains(position) {
+            Entry::Occupied(OccupiedEntry {
+                position,
+                item: self.get_mut(position).expect("Point must be in boundary."),
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                position,
+                array: self,
+            })
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Errors returned by fallible constructors.
+
+use core::fmt::{self, Display};
+
+use crate::{Coordinate, GenericBoundary};
+
+/// Error returned by `try_*` constructors and deserialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Array2dError {
+    /// The storage has less items than required by the boundary and pitch.
+    NotEnoughItems { needed: usize, got: usize },
+    /// The pitch is less than the width of the boundary.
+    PitchTooSmall { pitch: usize, width: usize },
+    /// The number of items required does not fit in a `usize`.
+    DimensionOverflow,
+}
+
+impl Display for Array2dError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Array2dError::NotEnoughItems { needed, got } => {
+                write!(f, "Not enough items, needed {needed}, got {got}.")
+            }
+            Array2dError::PitchTooSmall { pitch, width } => {
+                write!(f, "Pitch {pitch} must not be less than width {width}.")
+            }
+            Array2dError::DimensionOverflow => write!(f, "Dimension overflows usize."),
+        }
+    }
+}
+
+impl core::error::Error for Array2dError {}
+
+/// Check if a storage of `len` items can back `boundary` with `pitch`.
+pub(crate) fn validate<C: Coordinate>(
+    len: usize,
+    boundary: GenericBoundary<C>,
+    pitch: usize,
+) -> Result<(), Array2dError> {
+    let width = checked_usize::<C>(boundary.dimension.x)?;
+    if pitch < width {
+        return Err(Array2dError::PitchTooSmall { pitch, width });
+    }
+    let needed = checked_usize::<C>(boundary.dimension.y)?
+        .checked_mul(pitch)
+        .ok_or(Array2dError::DimensionOverflow)?;
+    if len < needed {
+        return Err(Array2dError::NotEnoughItems { needed, got: len });
+    }
+    Ok(())
+}
+
+/// Convert a dimension to `usize` without truncating on narrow targets.
+pub(crate) fn checked_usize<C: Coordinate>(value: C::Unsigned) -> Result<usize, Array2dError> {
+    usize::try_from(C::unsigned_to_i128(value)).map_err(|_| Array2dError::DimensionOverflow)
+}
@@ -0,0 +1,127 @@
+//! Stack allocated arrays with a size known at compile time.
+
+use mint::Vector2;
+
+use crate::{
+    Boundary, GenericArray2d,
+    storage::{
+        Array2dStorage, Array2dStorageMut, Array2dStorageRef, Array2dStorageSlice,
+        Array2dStorageSliceMut, strided, strided_mut,
+    },
+};
+
+/// A stack allocated 2d array with `W` columns and `H` rows.
+///
+/// Useful for small stencils, kernels and prefabs,
+/// works as a brush for [`paint`](GenericArray2d::paint) and in [`Zip`](crate::Zip).
+pub type FixedArray2d<T, const W: usize, const H: usize> = GenericArray2d<[[T; W]; H]>;
+
+impl<T, const W: usize, const H: usize> Array2dStorage for [[T; W]; H] {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        W * H
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.as_flattened().get(index).cloned()
+    }
+}
+
+impl<T, const W: usize, const H: usize> Array2dStorageRef for [[T; W]; H] {
+    fn get(&self, index: usize) -> Option<&Self::Item> {
+        self.as_flattened().get(index)
+    }
+
+    fn iter_strided(
+        &self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &Self::Item> {
+        strided(self.as_flattened(), start, width, pitch, height)
+    }
+}
+
+impl<T, const W: usize, const H: usize> Array2dStorageMut for [[T; W]; H] {
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+        self.as_flattened_mut().get_mut(index)
+    }
+
+    fn iter_strided_mut(
+        &mut self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &mut Self::Item> {
+        strided_mut(self.as_flattened_mut(), start, width, pitch, height)
+    }
+}
+
+impl<T, const W: usize, const H: usize> Array2dStorageSlice for [[T; W]; H] {
+    fn slice(&self) -> &[Self::Item] {
+        self.as_flattened()
+    }
+}
+
+impl<T, const W: usize, const H: usize> Array2dStorageSliceMut for [[T; W]; H] {
+    fn slice_mut(&mut self) -> &mut [Self::Item] {
+        self.as_flattened_mut()
+    }
+}
+
+impl<T: Default, const W: usize, const H: usize> Default for FixedArray2d<T, W, H> {
+    fn default() -> Self {
+        Self::from_rows(core::array::from_fn(|_| {
+            core::array::from_fn(|_| T::default())
+        }))
+    }
+}
+
+impl<T, const W: usize, const H: usize> FixedArray2d<T, W, H> {
+    /// Create a [`FixedArray2d`] from rows, with `boundary.min` at `[0, 0]`.
+    pub fn from_rows(rows: [[T; W]; H]) -> Self {
+        Self::from_rows_at(rows, [0, 0])
+    }
+
+    /// Create a [`FixedArray2d`] from rows, with `boundary.min` at `min`.
+    pub fn from_rows_at(rows: [[T; W]; H], min: impl Into<Vector2<i32>>) -> Self {
+        GenericArray2d {
+            data: rows,
+            boundary: Boundary {
+                min: min.into(),
+                dimension: Vector2 {
+                    x: W as u32,
+                    y: H as u32,
+                },
+            },
+            pitch: W,
+            offset: 0,
+        }
+    }
+
+    /// Initialize a [`FixedArray2d`] with a function, with `boundary.min` at `[0, 0]`.
+    pub fn from_fn<U: From<Vector2<i32>>>(mut init: impl FnMut(U) -> T) -> Self {
+        Self::from_rows(core::array::from_fn(|y| {
+            core::array::from_fn(|x| {
+                init(
+                    Vector2 {
+                        x: x as i32,
+                        y: y as i32,
+                    }
+                    .into(),
+                )
+            })
+        }))
+    }
+
+    /// Convert into rows.
+    pub fn into_rows(self) -> [[T; W]; H] {
+        self.data
+    }
+}
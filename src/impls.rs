@@ -1,10 +1,17 @@
-use crate::index::Array2dIndexing;
-use crate::storage::{Array2dStorage, Array2dStorageMut, Array2dStorageOwned};
+use crate::index::{Array2dIndexing, Array2dIndexingMut};
+use crate::storage::{
+    Array2dStorage, Array2dStorageMut, Array2dStorageOwned, Array2dStorageRef, Array2dStorageSet,
+    Array2dStorageSlice, Array2dStorageSliceMut,
+};
 use crate::util::*;
-use crate::{Array2dMut, Array2dRef, Boundary, GenericArray2d, IntoBoundary};
+use crate::{
+    Array2dError, Coordinate, GenericArray2d, GenericBoundary, IntoBoundary,
+    error::{checked_usize, validate},
+};
+use alloc::vec::Vec;
 use mint::Vector2;
 
-impl<T: Array2dStorage> GenericArray2d<T> {
+impl<T: Array2dStorage, C: Coordinate> GenericArray2d<T, C> {
     /// Returns true if contains no items.
     pub fn is_empty(&self) -> bool {
         self.boundary.is_empty()
@@ -17,12 +24,12 @@ impl<T: Array2dStorage> GenericArray2d<T> {
 
     /// Returns the width of the array.
     pub fn width(&self) -> usize {
-        self.boundary.dimension.x as usize
+        self.boundary.pitch()
     }
 
     /// Returns the height of the array.
     pub fn height(&self) -> usize {
-        self.boundary.dimension.y as usize
+        self.boundary.height()
     }
 
     /// Returns the pitch of the array.
@@ -31,7 +38,7 @@ impl<T: Array2dStorage> GenericArray2d<T> {
     }
 
     /// Returns the numerically smallest coordinate in the array.
-    pub fn min_point<U: From<Vector2<i32>>>(&self) -> U {
+    pub fn min_point<U: From<Vector2<C>>>(&self) -> U {
         self.boundary.min.into()
     }
 
@@ -40,7 +47,7 @@ impl<T: Array2dStorage> GenericArray2d<T> {
     /// # Note
     ///
     /// Equivalent to `min_point + dimension - [1, 1]`.
-    pub fn max_point<U: From<Vector2<i32>>>(&self) -> U {
+    pub fn max_point<U: From<Vector2<C>>>(&self) -> U {
         self.boundary.max().into()
     }
 
@@ -49,195 +56,432 @@ impl<T: Array2dStorage> GenericArray2d<T> {
     /// # Note
     ///
     /// Equivalent to `max_point - min_point + [1, 1]`.
-    pub fn dimension<U: From<Vector2<u32>>>(&self) -> U {
+    pub fn dimension<U: From<Vector2<C::Unsigned>>>(&self) -> U {
         self.boundary.dimension.into()
     }
 
     /// Returns the boundary of the array.
-    pub fn boundary(&self) -> Boundary {
+    pub fn boundary(&self) -> GenericBoundary<C> {
         self.boundary
     }
 
     /// Returns true if a point is in boundary.
-    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+    pub fn contains(&self, position: impl Into<Vector2<C>>) -> bool {
         self.boundary.contains(position)
     }
 
     /// Returns either a point or a slice via [`IntoBoundary`].
     ///
-    /// Unlike [`slice`](GenericArray2d::slice), this only returns `Some` if all points are contained in the array.
-    pub fn get<I: Array2dIndexing<M>, M>(&self, point: I) -> I::Result<'_, T::Item> {
+    /// Unlike [`slice`](GenericArray2d::slice), this only returns `Some` if all points are contained in the array,
+    /// an empty boundary always returns an empty `Some`.
+    pub fn get<I: Array2dIndexing<M, T, C>, M>(&self, point: I) -> I::Result<'_> {
         point.index(self)
     }
 
-    /// Shorthand to `self.get(point).cloned().unwrap_or_default()`.
-    pub fn fetch(&self, point: impl Into<Vector2<i32>>) -> T::Item
+    /// Returns a clone of a point, works on storages that cannot return references.
+    pub fn get_cloned(&self, point: impl Into<Vector2<C>>) -> Option<T::Item>
+    where
+        T::Item: Clone,
+    {
+        let index = self.index_of(point.into())?;
+        self.data.get_cloned(index)
+    }
+
+    /// Shorthand to `self.get_cloned(point).unwrap_or_default()`.
+    pub fn fetch(&self, point: impl Into<Vector2<C>>) -> T::Item
     where
         T::Item: Clone + Default,
     {
-        point.index(self).cloned().unwrap_or_default()
+        self.get_cloned(point).unwrap_or_default()
+    }
+
+    /// Iterate through pairs of points and cloned values in the array,
+    /// works on storages that cannot return references.
+    pub fn iter_cloned<U: From<Vector2<C>>>(&self) -> impl Iterator<Item = (U, T::Item)>
+    where
+        T::Item: Clone,
+    {
+        let min = self.boundary.min;
+        DimensionIter::<C>::new(self.boundary.dimension)
+            .map(move |x| (U::from(addu(min, x)), x))
+            .map(|(position, x)| {
+                let index = self.offset
+                    + C::unsigned_to_usize(x.y) * self.pitch
+                    + C::unsigned_to_usize(x.x);
+                let item = self.data.get_cloned(index).expect("Index out of bounds.");
+                (position, item)
+            })
+    }
+
+    /// Returns clones of all values in the array,
+    /// works on storages that cannot return references.
+    pub fn values_cloned(&self) -> impl Iterator<Item = T::Item>
+    where
+        T::Item: Clone,
+    {
+        self.iter_cloned::<Vector2<C>>().map(|(_, v)| v)
+    }
+
+    #[track_caller]
+    /// Create an array from a row major storage and a pitch value.
+    pub fn from_storage_pitch(data: T, boundary: impl IntoBoundary<C>, pitch: usize) -> Self {
+        Self::try_from_storage_pitch(data, boundary, pitch).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create an array from a row major storage and a pitch value,
+    /// returns an error instead of panicking.
+    pub fn try_from_storage_pitch(
+        data: T,
+        boundary: impl IntoBoundary<C>,
+        pitch: usize,
+    ) -> Result<Self, Array2dError> {
+        let boundary = boundary.into_boundary();
+        validate(data.len(), boundary, pitch)?;
+        Ok(GenericArray2d {
+            data,
+            boundary,
+            pitch,
+            offset: 0,
+        })
+    }
+
+    /// Convert into the underlying storage.
+    pub fn into_storage(self) -> T {
+        self.data
+    }
+
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector2<C>>) {
+        self.boundary.min = add(self.boundary.min, by.into());
     }
 
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displaced(mut self, by: impl Into<Vector2<C>>) -> Self {
+        self.boundary.min = add(self.boundary.min, by.into());
+        self
+    }
+}
+
+impl<T: Array2dStorageSet, C: Coordinate> GenericArray2d<T, C> {
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, point: impl Into<Vector2<C>>, value: T::Item) -> bool {
+        match self.index_of(point.into()) {
+            Some(index) => self.data.set(index, value),
+            None => false,
+        }
+    }
+}
+
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2d<T, C> {
     /// Iterate through pairs of points and values in the array.
-    pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, &T::Item)> {
+    pub fn iter<U: From<Vector2<C>>>(&self) -> impl Iterator<Item = (U, &T::Item)> {
         let min = self.boundary.min;
-        DimensionIter::new(self.boundary.dimension)
-            .map(move |x| add(x, min))
+        DimensionIter::<C>::new(self.boundary.dimension)
+            .map(move |x| addu(min, x))
             .map(|x| U::from(x))
             .zip(self.values())
     }
 
+    /// Returns all values in the array.
+    pub fn values(&self) -> impl Iterator<Item = &T::Item> {
+        self.data
+            .iter_strided(self.offset, self.width(), self.pitch, self.height())
+    }
+
+    /// Returns values in the `y`-th row from the top.
+    pub(crate) fn row_values(&self, y: usize) -> impl Iterator<Item = &T::Item> {
+        self.data
+            .iter_strided(self.offset + y * self.pitch, self.width(), self.pitch, 1)
+    }
+
+    /// Returns strided iterators defined by the minor axis.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T::Item>> {
+        let (offset, height, pitch) = (self.offset, self.height(), self.pitch);
+        (0..self.width()).map(move |x| self.data.iter_strided(offset + x, 1, pitch, height))
+    }
+}
+
+impl<T: Array2dStorageSlice, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the underlying buffer starting from `boundary.min`.
+    pub(crate) fn buffer(&self) -> &[T::Item] {
+        &self.data.slice()[self.offset..]
+    }
+
+    /// Returns a reference to a point without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The point must be contained in the array's boundary.
+    pub unsafe fn get_unchecked(&self, point: impl Into<Vector2<C>>) -> &T::Item {
+        let offset = offset_of(point.into(), self.boundary.min, self.pitch);
+        // Safety: guaranteed by caller.
+        unsafe { self.buffer().get_unchecked(offset) }
+    }
+
     /// Returns continuous slices defined by the major axis.
     pub fn rows(&self) -> impl Iterator<Item = &[T::Item]> {
-        let slice = self.data.slice();
-        let len = self.boundary.dimension.x as usize;
+        let slice = self.buffer();
+        let len = self.width();
         slice
             .chunks(self.pitch.max(1))
             .map(move |slice| &slice[..len])
-            .take(self.boundary.dimension.y as usize)
-    }
-
-    /// Returns all values in the array.
-    pub fn values(&self) -> impl Iterator<Item = &T::Item> {
-        self.rows().flatten()
+            .take(self.height())
     }
 
     /// Obtain a truncated subslice.
     ///
     /// Unlike `get`, returns a truncated result if out of bounds.
-    pub fn slice(&self, boundary: impl IntoBoundary) -> Array2dRef<T::Item> {
+    pub fn slice(&self, boundary: impl IntoBoundary<C>) -> GenericArray2d<&[T::Item], C> {
         self.slice_internal(boundary.into_boundary()).1
     }
-
-    /// Move the origin point of the array without affecting underlying data.
-    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
-        self.boundary.min = add(self.boundary.min, by.into());
-    }
-
-    /// Move the origin point of the array without affecting underlying data.
-    pub fn displaced(mut self, by: impl Into<Vector2<i32>>) -> Self {
-        self.boundary.min = add(self.boundary.min, by.into());
-        self
-    }
 }
 
-impl<T: Array2dStorageMut> GenericArray2d<T> {
+impl<T: Array2dStorageMut, C: Coordinate> GenericArray2d<T, C> {
     /// Returns either a point or a slice via [`IntoBoundary`].
     ///
-    /// Unlike [`slice_mut`](GenericArray2d::slice_mut), this only returns `Some` if all points are contained in the array.
-    pub fn get_mut<I: Array2dIndexing<M>, M>(&mut self, point: I) -> I::ResultMut<'_, T::Item> {
+    /// Unlike [`slice_mut`](GenericArray2d::slice_mut), this only returns `Some` if all points are contained in the array,
+    /// an empty boundary always returns an empty `Some`.
+    pub fn get_mut<I: Array2dIndexingMut<M, T, C>, M>(&mut self, point: I) -> I::ResultMut<'_> {
         point.index_mut(self)
     }
 
-    /// Try set a position to a value, returns `true`.
-    pub fn set(&mut self, point: impl Into<Vector2<i32>>, value: T::Item) -> bool {
-        if let Some(v) = point.index_mut(self) {
-            *v = value;
-            true
-        } else {
-            false
-        }
-    }
-
     /// Iterate through pairs of points and values in the array.
-    pub fn iter_mut<U: From<Vector2<i32>>>(&mut self) -> impl Iterator<Item = (U, &mut T::Item)> {
+    pub fn iter_mut<U: From<Vector2<C>>>(&mut self) -> impl Iterator<Item = (U, &mut T::Item)> {
         let min = self.boundary.min;
-        DimensionIter::new(self.boundary.dimension)
-            .map(move |x| add(x, min))
+        DimensionIter::<C>::new(self.boundary.dimension)
+            .map(move |x| addu(min, x))
             .map(|x| U::from(x))
             .zip(self.values_mut())
     }
 
-    /// Returns continuous slices defined by the major axis.
-    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T::Item]> {
-        let slice = self.data.slice_mut();
-        let len = self.boundary.dimension.x as usize;
-        slice
-            .chunks_mut(self.pitch.max(1))
-            .map(move |slice| &mut slice[..len])
-            .take(self.boundary.dimension.x as usize)
-    }
-
     /// Returns all values in the array.
     pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T::Item> {
-        self.rows_mut().flatten()
+        let (width, height) = (self.width(), self.height());
+        self.data
+            .iter_strided_mut(self.offset, width, self.pitch, height)
     }
 
+    /// Set all values in the array.
     pub fn fill(&mut self, value: T::Item)
     where
         T::Item: Clone,
     {
-        for row in self.rows_mut() {
-            for item in row {
-                *item = value.clone();
-            }
+        for item in self.values_mut() {
+            *item = value.clone();
         }
     }
 
-    /// Obtain a truncated subslice.
-    ///
-    /// Unlike `get`, returns a truncated result if out of bounds.
-    pub fn slice_mut(&mut self, boundary: impl IntoBoundary) -> Array2dMut<T::Item> {
-        self.slice_mut_internal(boundary.into_boundary()).1
-    }
-
     /// Modify a region with another array as a "brush".
     pub fn paint<U>(
         &mut self,
-        brush: &GenericArray2d<impl Array2dStorage<Item = U>>,
-        at: impl Into<Vector2<i32>>,
+        brush: &GenericArray2d<impl Array2dStorageRef<Item = U>, C>,
+        at: impl Into<Vector2<C>>,
         mut paint_fn: impl FnMut(&mut T::Item, &U),
     ) {
         let at = at.into();
-        let region = Boundary {
+        let region = GenericBoundary {
             min: add(brush.boundary.min, at),
             dimension: brush.boundary.dimension,
         };
         let Some(intersection) = self.boundary.intersection(region) else {
             return;
         };
-        let mut base1 = offset_of(intersection.min, self.boundary.min, self.pitch);
-        let mut base2 = offset_of(intersection.min, region.min, brush.pitch);
-
-        for _ in 0..intersection.dimension.y as usize {
-            for i in 0..intersection.dimension.x as usize {
-                paint_fn(
-                    &mut self.data.slice_mut()[base1 + i],
-                    &brush.data.slice()[base2 + i],
-                )
+        let base1 = self.offset + offset_of(intersection.min, self.boundary.min, self.pitch);
+        let base2 = brush.offset + offset_of(intersection.min, region.min, brush.pitch);
+        let width = intersection.pitch();
+        let height = intersection.height();
+        let source = brush.data.iter_strided(base2, width, brush.pitch, height);
+        let target = self.data.iter_strided_mut(base1, width, self.pitch, height);
+        for (a, b) in target.zip(source) {
+            paint_fn(a, b)
+        }
+    }
+}
+
+impl<T: Array2dStorageSliceMut, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the underlying buffer starting from `boundary.min`.
+    pub(crate) fn buffer_mut(&mut self) -> &mut [T::Item] {
+        &mut self.data.slice_mut()[self.offset..]
+    }
+
+    /// Returns a mutable reference to a point without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The point must be contained in the array's boundary.
+    pub unsafe fn get_unchecked_mut(&mut self, point: impl Into<Vector2<C>>) -> &mut T::Item {
+        let offset = offset_of(point.into(), self.boundary.min, self.pitch);
+        // Safety: guaranteed by caller.
+        unsafe { self.buffer_mut().get_unchecked_mut(offset) }
+    }
+
+    /// Returns continuous slices defined by the major axis.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T::Item]> {
+        let (len, height) = (self.width(), self.height());
+        let slice = &mut self.data.slice_mut()[self.offset..];
+        slice
+            .chunks_mut(self.pitch.max(1))
+            .map(move |slice| &mut slice[..len])
+            .take(height)
+    }
+
+    /// Obtain a truncated subslice.
+    ///
+    /// Unlike `get`, returns a truncated result if out of bounds.
+    pub fn slice_mut(
+        &mut self,
+        boundary: impl IntoBoundary<C>,
+    ) -> GenericArray2d<&mut [T::Item], C> {
+        self.slice_mut_internal(boundary.into_boundary()).1
+    }
+
+    /// Copy a region to another position in the same array, handles overlapping correctly.
+    ///
+    /// Points outside of the array in either the source or the destination are ignored.
+    pub fn copy_within(&mut self, src: impl IntoBoundary<C>, dest_min: impl Into<Vector2<C>>)
+    where
+        T::Item: Copy,
+    {
+        let src = src.into_boundary();
+        self.within_internal(
+            src,
+            wide_sub(dest_min.into(), src.min),
+            |slice, from, to, len| slice.copy_within(from..from + len, to),
+        );
+    }
+
+    /// Move a region to another position in the same array, handles overlapping correctly.
+    ///
+    /// Points of the source that are not overwritten are left as [`Default::default`],
+    /// including points moved outside of the array.
+    pub fn move_within(&mut self, src: impl IntoBoundary<C>, dest_min: impl Into<Vector2<C>>)
+    where
+        T::Item: Default,
+    {
+        let src = src.into_boundary();
+        self.move_within_internal(src, wide_sub(dest_min.into(), src.min));
+    }
+
+    /// Shift the content of the array by an offset without changing the boundary.
+    ///
+    /// Points shifted out of the array are discarded and
+    /// newly exposed points are filled with [`Default::default`].
+    pub fn scroll(&mut self, by: impl Into<Vector2<C>>)
+    where
+        T::Item: Default,
+    {
+        let by = by.into();
+        self.move_within_internal(
+            self.boundary,
+            Vector2 {
+                x: by.x.to_i128(),
+                y: by.y.to_i128(),
+            },
+        );
+    }
+
+    fn move_within_internal(&mut self, src: GenericBoundary<C>, by: Vector2<i128>)
+    where
+        T::Item: Default,
+    {
+        let dest = self.within_internal(src, by, move_within);
+        let Some(src) = src.intersection(self.boundary) else {
+            return;
+        };
+        for (position, item) in self.slice_mut(src).iter_mut::<Vector2<C>>() {
+            if !dest.is_some_and(|dest| dest.contains(position)) {
+                *item = Default::default();
             }
-            base1 += self.pitch;
-            base2 += brush.pitch;
         }
     }
+
+    /// Returns the destination region if not empty.
+    fn within_internal(
+        &mut self,
+        src: GenericBoundary<C>,
+        by: Vector2<i128>,
+        mut f: impl FnMut(&mut [T::Item], usize, usize, usize),
+    ) -> Option<GenericBoundary<C>> {
+        let src = src.intersection(self.boundary)?;
+        let dest = src
+            .saturating_displace_wide(by)
+            .intersection(self.boundary)
+            .filter(|dest| !dest.is_empty())?;
+        let src = dest.saturating_displace_wide(Vector2 { x: -by.x, y: -by.y });
+        let mut from = offset_of(src.min, self.boundary.min, self.pitch);
+        let mut to = offset_of(dest.min, self.boundary.min, self.pitch);
+        let len = dest.pitch();
+        let slice = &mut self.data.slice_mut()[self.offset..];
+        if by.y > 0 {
+            // Copy rows from bottom to top to avoid overwriting unread rows.
+            let last = dest.height().saturating_sub(1) * self.pitch;
+            from += last;
+            to += last;
+            for _ in 0..dest.height() {
+                f(slice, from, to, len);
+                from = from.wrapping_sub(self.pitch);
+                to = to.wrapping_sub(self.pitch);
+            }
+        } else {
+            for _ in 0..dest.height() {
+                f(slice, from, to, len);
+                from += self.pitch;
+                to += self.pitch;
+            }
+        }
+        Some(dest)
+    }
 }
 
-impl<T: Array2dStorageOwned> GenericArray2d<T> {
+impl<T: Array2dStorageOwned, C: Coordinate> GenericArray2d<T, C> {
     /// Initialize an array2d with a function.
-    pub fn init<U: From<Vector2<i32>>>(
-        boundary: impl IntoBoundary,
+    pub fn init<U: From<Vector2<C>>>(
+        boundary: impl IntoBoundary<C>,
         mut init: impl FnMut(U) -> T::Item,
     ) -> Self {
         let boundary = boundary.into_boundary();
         let len = boundary.len();
         let mut vec = Vec::with_capacity(len);
         vec.extend(
-            DimensionIter::new(boundary.dimension).map(|v| init(add(v, boundary.min).into())),
+            DimensionIter::<C>::new(boundary.dimension).map(|v| init(addu(boundary.min, v).into())),
         );
         Self {
             data: T::from_vec(vec),
             boundary,
             pitch: boundary.pitch(),
+            offset: 0,
         }
     }
 
-    /// Returns the underlying buffer as a slice, may include padding items in `pitch`.
-    pub fn underlying_slice(&self) -> &[T::Item] {
-        self.data.slice()
+    /// Returns the allocated region of the array, always contains the boundary.
+    ///
+    /// Growing the array within the capacity does not reallocate.
+    ///
+    /// The region is the whole buffer laid out with the current pitch,
+    /// [`resize`](GenericArray2d::resize) keeps the buffer of a [`Vec`] but narrows the pitch,
+    /// so after shrinking the capacity may be much taller than the array has ever been.
+    /// Use [`shrink_to_fit`](GenericArray2d::shrink_to_fit) to release it.
+    pub fn capacity(&self) -> GenericBoundary<C> {
+        if self.pitch == 0 {
+            return self.boundary;
+        }
+        let rows = self.data.len() / self.pitch;
+        let min = Vector2 {
+            x: self.boundary.min.x.to_i128() - (self.offset % self.pitch) as i128,
+            y: self.boundary.min.y.to_i128() - (self.offset / self.pitch) as i128,
+        };
+        // A user supplied pitch may exceed the coordinate range, clamp instead of wrapping.
+        GenericBoundary::saturating_from_wide(
+            min,
+            Vector2 {
+                x: min.x + self.pitch as i128,
+                y: min.y + rows as i128,
+            },
+        )
     }
 
     /// Create an `Array2d` filled with a value.
-    pub fn new_filled(boundary: impl IntoBoundary, fill: T::Item) -> Self
+    pub fn new_filled(boundary: impl IntoBoundary<C>, fill: T::Item) -> Self
     where
         T::Item: Clone,
     {
@@ -249,42 +493,51 @@ impl<T: Array2dStorageOwned> GenericArray2d<T> {
             data: T::from_vec(vec),
             boundary,
             pitch: boundary.pitch(),
+            offset: 0,
         }
     }
 
     #[track_caller]
     /// Create an `Array2d` from a row major [`Vec`] as the underlying storage.
-    pub fn from_vec(vec: Vec<T::Item>, boundary: impl IntoBoundary) -> Self {
+    pub fn from_vec(vec: Vec<T::Item>, boundary: impl IntoBoundary<C>) -> Self {
+        Self::try_from_vec(vec, boundary).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create an `Array2d` from a row major [`Vec`] as the underlying storage,
+    /// returns an error instead of panicking.
+    pub fn try_from_vec(
+        vec: Vec<T::Item>,
+        boundary: impl IntoBoundary<C>,
+    ) -> Result<Self, Array2dError> {
         let boundary = boundary.into_boundary();
-        assert!(vec.len() >= boundary.len(), "Not enough items.");
-        GenericArray2d {
-            data: T::from_vec(vec),
-            boundary,
-            pitch: boundary.pitch(),
-        }
+        Self::try_from_vec_pitch(vec, boundary, checked_usize::<C>(boundary.dimension.x)?)
     }
 
     #[track_caller]
     /// Create an `Array2d` from a row major [`Vec`] as the underlying storage and a pitch value.
-    pub fn from_vec_pitch(vec: Vec<T::Item>, boundary: impl IntoBoundary, pitch: usize) -> Self {
+    pub fn from_vec_pitch(vec: Vec<T::Item>, boundary: impl IntoBoundary<C>, pitch: usize) -> Self {
+        Self::try_from_vec_pitch(vec, boundary, pitch).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create an `Array2d` from a row major [`Vec`] as the underlying storage and a pitch value,
+    /// returns an error instead of panicking.
+    pub fn try_from_vec_pitch(
+        vec: Vec<T::Item>,
+        boundary: impl IntoBoundary<C>,
+        pitch: usize,
+    ) -> Result<Self, Array2dError> {
         let boundary = boundary.into_boundary();
-        assert!(
-            vec.len() >= boundary.dimension.y as usize * pitch,
-            "Not enough items."
-        );
-        assert!(
-            pitch >= boundary.pitch(),
-            "Pitch must be larger than boundary."
-        );
-        GenericArray2d {
+        validate(vec.len(), boundary, pitch)?;
+        Ok(GenericArray2d {
             data: T::from_vec(vec),
             boundary,
             pitch,
-        }
+            offset: 0,
+        })
     }
 
     /// Create an `Array2d` with [`Default`] values.
-    pub fn new(boundary: impl IntoBoundary) -> Self
+    pub fn new(boundary: impl IntoBoundary<C>) -> Self
     where
         T::Item: Default,
     {
@@ -296,117 +549,157 @@ impl<T: Array2dStorageOwned> GenericArray2d<T> {
             data: T::from_vec(vec),
             boundary,
             pitch: boundary.pitch(),
+            offset: 0,
         }
     }
 
     /// Clear items in the array.
     pub fn clear(&mut self) {
-        self.boundary = Boundary::EMPTY;
-        self.data.vec_mut().clear();
+        self.boundary = GenericBoundary::EMPTY;
+        match self.data.vec_mut() {
+            Some(vec) => vec.clear(),
+            None => self.data = T::from_vec(Vec::new()),
+        }
         self.pitch = 0;
-    }
-
-    /// Returns the array as a [`Array2dRef`].
-    pub fn as_slice(&self) -> Array2dRef<T::Item> {
-        self.slice(..)
-    }
-
-    /// Returns the array as a [`Array2dMut`].
-    pub fn as_slice_mut(&mut self) -> Array2dMut<T::Item> {
-        self.slice_mut(..)
+        self.offset = 0;
     }
 
     /// Iterate through owned pairs of points and values in the array.
     ///
     /// Equivalent to `into_iter` for `Vec`.
-    pub fn iter_owned<U: From<Vector2<i32>>>(self) -> impl Iterator<Item = (U, T::Item)> {
+    pub fn iter_owned<U: From<Vector2<C>>>(self) -> impl Iterator<Item = (U, T::Item)> {
         let min = self.boundary.min;
+        let dimension = Vector2 {
+            x: self.width(),
+            y: self.height(),
+        };
         IterOwned {
-            iter: self.data.into_owned_iter(),
+            iter: self.data.into_owned_iter().skip(self.offset),
             position: Vector2 { x: 0, y: 0 },
-            dimension: self.boundary.dimension,
-            pitch: self.pitch as u32,
+            dimension,
+            pitch: self.pitch,
         }
-        .map(move |(i, v)| (add(i, min).into(), v))
+        .map(move |(i, v)| (from_local(min, i.x, i.y).into(), v))
     }
 }
 
-impl<'t, T> Array2dRef<'t, T> {
-    /// Create a [`Array2dRef`] by reinterpreting a row major slice.
+impl<T: Array2dStorageOwned + Array2dStorageSliceMut, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the underlying buffer as a slice, may include padding items in `pitch`.
+    pub fn underlying_slice(&self) -> &[T::Item] {
+        self.buffer()
+    }
+
+    /// Returns the array as a [`Array2dRef`](crate::Array2dRef).
+    pub fn as_slice(&self) -> GenericArray2d<&[T::Item], C> {
+        self.slice(..)
+    }
+
+    /// Returns the array as a [`Array2dMut`](crate::Array2dMut).
+    pub fn as_slice_mut(&mut self) -> GenericArray2d<&mut [T::Item], C> {
+        self.slice_mut(..)
+    }
+}
+
+impl<'t, T, C: Coordinate> GenericArray2d<&'t [T], C> {
+    /// Create a [`Array2dRef`](crate::Array2dRef) by reinterpreting a row major slice.
     ///
     /// # Panics
     ///
     /// If the slice has less items than the boundary.
-    pub fn from_slice(slice: &'t [T], boundary: impl IntoBoundary) -> Self {
+    #[track_caller]
+    pub fn from_slice(slice: &'t [T], boundary: impl IntoBoundary<C>) -> Self {
+        Self::try_from_slice(slice, boundary).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a [`Array2dRef`](crate::Array2dRef) by reinterpreting a row major slice,
+    /// returns an error instead of panicking.
+    pub fn try_from_slice(
+        slice: &'t [T],
+        boundary: impl IntoBoundary<C>,
+    ) -> Result<Self, Array2dError> {
         let boundary = boundary.into_boundary();
-        assert!(slice.len() >= boundary.len(), "Not enough items.");
-        Array2dRef {
-            data: slice,
-            boundary,
-            pitch: boundary.pitch(),
-        }
+        Self::try_from_slice_pitch(slice, boundary, checked_usize::<C>(boundary.dimension.x)?)
     }
 
-    /// Create a [`Array2dRef`] by reinterpreting a row major slice, the offset between each row is `pitch`.
+    /// Create a [`Array2dRef`](crate::Array2dRef) by reinterpreting a row major slice, the offset between each row is `pitch`.
     ///
     /// # Panics
     ///
     /// * If the slice has less items than the boundary.
     /// * If the pitch is less the boundary width.
-    pub fn from_slice_pitch(slice: &'t [T], boundary: impl IntoBoundary, pitch: usize) -> Self {
+    #[track_caller]
+    pub fn from_slice_pitch(slice: &'t [T], boundary: impl IntoBoundary<C>, pitch: usize) -> Self {
+        Self::try_from_slice_pitch(slice, boundary, pitch).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a [`Array2dRef`](crate::Array2dRef) by reinterpreting a row major slice, the offset between each row is `pitch`,
+    /// returns an error instead of panicking.
+    pub fn try_from_slice_pitch(
+        slice: &'t [T],
+        boundary: impl IntoBoundary<C>,
+        pitch: usize,
+    ) -> Result<Self, Array2dError> {
         let boundary = boundary.into_boundary();
-        assert!(
-            slice.len() >= boundary.dimension.y as usize * pitch,
-            "Not enough items."
-        );
-        assert!(
-            pitch >= boundary.pitch(),
-            "Pitch must be larger than boundary."
-        );
-        Array2dRef {
+        validate(slice.len(), boundary, pitch)?;
+        Ok(GenericArray2d {
             data: slice,
             boundary,
             pitch,
-        }
+            offset: 0,
+        })
     }
 }
 
-impl<'t, T> Array2dMut<'t, T> {
-    /// Create a [`Array2dMut`] by reinterpreting a row major slice.
+impl<'t, T, C: Coordinate> GenericArray2d<&'t mut [T], C> {
+    /// Create a [`Array2dMut`](crate::Array2dMut) by reinterpreting a row major slice.
     ///
     /// # Panics
     ///
     /// If the slice has less items than the boundary.
-    pub fn from_slice(slice: &'t mut [T], boundary: impl IntoBoundary) -> Self {
+    #[track_caller]
+    pub fn from_slice(slice: &'t mut [T], boundary: impl IntoBoundary<C>) -> Self {
+        Self::try_from_slice(slice, boundary).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a [`Array2dMut`](crate::Array2dMut) by reinterpreting a row major slice,
+    /// returns an error instead of panicking.
+    pub fn try_from_slice(
+        slice: &'t mut [T],
+        boundary: impl IntoBoundary<C>,
+    ) -> Result<Self, Array2dError> {
         let boundary = boundary.into_boundary();
-        assert!(slice.len() >= boundary.len(), "Not enough items.");
-        Array2dMut {
-            data: slice,
-            boundary,
-            pitch: boundary.pitch(),
-        }
+        Self::try_from_slice_pitch(slice, boundary, checked_usize::<C>(boundary.dimension.x)?)
     }
 
-    /// Create a [`Array2dMut`] by reinterpreting a row major slice, the offset between each row is `pitch`.
+    /// Create a [`Array2dMut`](crate::Array2dMut) by reinterpreting a row major slice, the offset between each row is `pitch`.
     ///
     /// # Panics
     ///
     /// * If the slice has less items than the boundary.
     /// * If the pitch is less the boundary width.
-    pub fn from_slice_pitch(slice: &'t mut [T], boundary: impl IntoBoundary, pitch: usize) -> Self {
+    #[track_caller]
+    pub fn from_slice_pitch(
+        slice: &'t mut [T],
+        boundary: impl IntoBoundary<C>,
+        pitch: usize,
+    ) -> Self {
+        Self::try_from_slice_pitch(slice, boundary, pitch).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a [`Array2dMut`](crate::Array2dMut) by reinterpreting a row major slice, the offset between each row is `pitch`,
+    /// returns an error instead of panicking.
+    pub fn try_from_slice_pitch(
+        slice: &'t mut [T],
+        boundary: impl IntoBoundary<C>,
+        pitch: usize,
+    ) -> Result<Self, Array2dError> {
         let boundary = boundary.into_boundary();
-        assert!(
-            slice.len() >= boundary.dimension.y as usize * pitch,
-            "Not enough items."
-        );
-        assert!(
-            pitch >= boundary.pitch(),
-            "Pitch must be larger than boundary."
-        );
-        Array2dMut {
+        validate(slice.len(), boundary, pitch)?;
+        Ok(GenericArray2d {
             data: slice,
             boundary,
             pitch,
-        }
+            offset: 0,
+        })
     }
 }
@@ -1,152 +1,195 @@
+use core::ops::{Index, IndexMut};
+
 use mint::Vector2;
 
 use crate::util::*;
 use crate::{
-    Array2dMut, Array2dRef, Boundary, GenericArray2d,
+    Coordinate, GenericArray2d, GenericBoundary,
     boundary::IntoBoundary,
-    storage::{Array2dStorage, Array2dStorageMut},
+    storage::{
+        Array2dStorage, Array2dStorageMut, Array2dStorageRef, Array2dStorageSlice,
+        Array2dStorageSliceMut,
+    },
 };
 
-/// Make an item usable in `get` and `get_mut`.
+/// Make an item usable in `get`.
 ///
-/// Implemented on `impl Into<mint::Vector<i32>>`,
-/// or `impl IntoBoundary`
+/// Implemented on `impl Into<mint::Vector<C>>` for all storages,
+/// or `impl IntoBoundary` for slice storages,
 /// using type inference, see [`IntoBoundary`].
 ///
 /// Any type that implements more than one of these will cause `get` to fail.
-pub trait Array2dIndexing<Marker> {
-    type Result<'t, T>
+pub trait Array2dIndexing<Marker, S: Array2dStorage, C: Coordinate = i32> {
+    type Result<'t>
     where
-        T: 't;
-    type ResultMut<'t, T>
+        S: 't;
+    fn index<'t>(self, array: &'t GenericArray2d<S, C>) -> Self::Result<'t>;
+}
+
+/// Make an item usable in `get_mut`.
+///
+/// See [`Array2dIndexing`].
+pub trait Array2dIndexingMut<Marker, S: Array2dStorageMut, C: Coordinate = i32>:
+    Array2dIndexing<Marker, S, C>
+{
+    type ResultMut<'t>
     where
-        T: 't;
-    fn index<'t, T: Array2dStorage>(
-        self,
-        array: &'t GenericArray2d<T>,
-    ) -> Self::Result<'t, T::Item>;
-    fn index_mut<'t, T: Array2dStorageMut>(
-        self,
-        array: &'t mut GenericArray2d<T>,
-    ) -> Self::ResultMut<'t, T::Item>;
+        S: 't;
+    fn index_mut<'t>(self, array: &'t mut GenericArray2d<S, C>) -> Self::ResultMut<'t>;
 }
 
 pub struct Vector2Marker;
 pub struct BoundaryMarker;
 
-impl<U: Into<Vector2<i32>>> Array2dIndexing<Vector2Marker> for U {
-    type Result<'t, T>
-        = Option<&'t T>
+impl<C: Coordinate, U: Into<Vector2<C>>, S: Array2dStorageRef> Array2dIndexing<Vector2Marker, S, C>
+    for U
+{
+    type Result<'t>
+        = Option<&'t S::Item>
     where
-        T: 't;
+        S: 't;
 
-    type ResultMut<'t, T>
-        = Option<&'t mut T>
-    where
-        T: 't;
-
-    fn index<'t, T: Array2dStorage>(
-        self,
-        array: &'t GenericArray2d<T>,
-    ) -> Self::Result<'t, T::Item> {
-        let v: Vector2<i32> = self.into();
-        let x = v.x - array.boundary.min.x;
-        let y = v.y - array.boundary.min.y;
-        if x < 0 || x >= array.boundary.dimension.x as i32 {
-            return None;
-        }
-        if y < 0 || y >= array.boundary.dimension.y as i32 {
-            return None;
-        }
-        array
-            .data
-            .slice()
-            .get(y as usize * array.pitch + x as usize)
+    fn index<'t>(self, array: &'t GenericArray2d<S, C>) -> Self::Result<'t> {
+        let index = array.index_of(self.into())?;
+        array.data.get(index)
     }
+}
 
-    fn index_mut<'t, T: Array2dStorageMut>(
-        self,
-        array: &'t mut GenericArray2d<T>,
-    ) -> Self::ResultMut<'t, T::Item> {
-        let v: Vector2<i32> = self.into();
-        let x = v.x - array.boundary.min.x;
-        let y = v.y - array.boundary.min.y;
-        if x < 0 || x >= array.boundary.dimension.x as i32 {
-            return None;
-        }
-        if y < 0 || y >= array.boundary.dimension.y as i32 {
-            return None;
-        }
-        array
-            .data
-            .slice_mut()
-            .get_mut(y as usize * array.pitch + x as usize)
+impl<C: Coordinate, U: Into<Vector2<C>>, S: Array2dStorageMut>
+    Array2dIndexingMut<Vector2Marker, S, C> for U
+{
+    type ResultMut<'t>
+        = Option<&'t mut S::Item>
+    where
+        S: 't;
+
+    fn index_mut<'t>(self, array: &'t mut GenericArray2d<S, C>) -> Self::ResultMut<'t> {
+        let index = array.index_of(self.into())?;
+        array.data.get_mut(index)
     }
 }
 
-impl<U: IntoBoundary> Array2dIndexing<BoundaryMarker> for U {
-    type Result<'t, T>
-        = Option<Array2dRef<'t, T>>
-    where
-        T: 't;
-    type ResultMut<'t, T>
-        = Option<Array2dMut<'t, T>>
+impl<C: Coordinate, U: IntoBoundary<C>, S: Array2dStorageSlice>
+    Array2dIndexing<BoundaryMarker, S, C> for U
+{
+    type Result<'t>
+        = Option<GenericArray2d<&'t [S::Item], C>>
     where
-        T: 't;
+        S: 't;
 
-    fn index<'t, T: Array2dStorage>(
-        self,
-        array: &'t GenericArray2d<T>,
-    ) -> Self::Result<'t, T::Item> {
+    fn index<'t>(self, array: &'t GenericArray2d<S, C>) -> Self::Result<'t> {
         let (ok, slice) = array.slice_internal(self.into_boundary());
         ok.then_some(slice)
     }
+}
+
+impl<C: Coordinate, U: IntoBoundary<C>, S: Array2dStorageSliceMut>
+    Array2dIndexingMut<BoundaryMarker, S, C> for U
+{
+    type ResultMut<'t>
+        = Option<GenericArray2d<&'t mut [S::Item], C>>
+    where
+        S: 't;
 
-    fn index_mut<'t, T: Array2dStorageMut>(
-        self,
-        array: &'t mut GenericArray2d<T>,
-    ) -> Self::ResultMut<'t, T::Item> {
+    fn index_mut<'t>(self, array: &'t mut GenericArray2d<S, C>) -> Self::ResultMut<'t> {
         let (ok, slice) = array.slice_mut_internal(self.into_boundary());
         ok.then_some(slice)
     }
 }
 
-impl<T: Array2dStorage> GenericArray2d<T> {
-    pub(crate) fn slice_internal(&self, input: Boundary) -> (bool, Array2dRef<T::Item>) {
-        if let Some(intersection) = self.boundary.intersection(input) {
-            let min = sub(intersection.min, self.boundary.min);
-            let offset = (min.y * self.pitch as i32 + min.x) as usize;
+impl<T: Array2dStorage, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the index of a point in the underlying storage if in boundary.
+    pub(crate) fn index_of(&self, v: Vector2<C>) -> Option<usize> {
+        // Points below `min` wrap to values larger than `dimension`.
+        let x = v.x.wrapping_sub(self.boundary.min.x).cast_unsigned();
+        let y = v.y.wrapping_sub(self.boundary.min.y).cast_unsigned();
+        if x >= self.boundary.dimension.x || y >= self.boundary.dimension.y {
+            return None;
+        }
+        Some(self.offset + C::unsigned_to_usize(y) * self.pitch + C::unsigned_to_usize(x))
+    }
+}
+
+impl<T: Array2dStorageSlice, C: Coordinate> GenericArray2d<T, C> {
+    pub(crate) fn slice_internal(
+        &self,
+        input: GenericBoundary<C>,
+    ) -> (bool, GenericArray2d<&[T::Item], C>) {
+        if let Some(intersection) = self.boundary.intersection(input)
+            && !intersection.is_empty()
+        {
+            let offset = offset_of(intersection.min, self.boundary.min, self.pitch);
             let is_perfect = intersection == input;
             (
                 is_perfect,
-                Array2dRef {
-                    data: &self.data.slice()[offset..],
+                GenericArray2d {
+                    data: &self.buffer()[offset..],
                     boundary: intersection,
                     pitch: self.pitch,
+                    offset: 0,
                 },
             )
         } else {
-            (false, Array2dRef::default())
+            (input.is_empty(), GenericArray2d::default())
         }
     }
 }
 
-impl<T: Array2dStorageMut> GenericArray2d<T> {
-    pub(crate) fn slice_mut_internal(&mut self, input: Boundary) -> (bool, Array2dMut<T::Item>) {
-        if let Some(intersection) = self.boundary.intersection(input) {
-            let min = sub(intersection.min, self.boundary.min);
-            let offset = (min.y * self.pitch as i32 + min.x) as usize;
+impl<T: Array2dStorageSliceMut, C: Coordinate> GenericArray2d<T, C> {
+    pub(crate) fn slice_mut_internal(
+        &mut self,
+        input: GenericBoundary<C>,
+    ) -> (bool, GenericArray2d<&mut [T::Item], C>) {
+        if let Some(intersection) = self.boundary.intersection(input)
+            && !intersection.is_empty()
+        {
+            let offset = offset_of(intersection.min, self.boundary.min, self.pitch);
             let is_perfect = intersection == input;
             (
                 is_perfect,
-                Array2dMut {
-                    data: &mut self.data.slice_mut()[offset..],
+                GenericArray2d {
+                    data: &mut self.data.slice_mut()[self.offset + offset..],
                     boundary: intersection,
                     pitch: self.pitch,
+                    offset: 0,
                 },
             )
         } else {
-            (false, Array2dMut::default())
+            (input.is_empty(), GenericArray2d::default())
+        }
+    }
+}
+
+impl<T: Array2dStorageRef, C: Coordinate, I: Into<Vector2<C>>> Index<I> for GenericArray2d<T, C> {
+    type Output = T::Item;
+
+    #[track_caller]
+    fn index(&self, index: I) -> &Self::Output {
+        let index: Vector2<C> = index.into();
+        let boundary = self.boundary;
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "Index [{:?}, {:?}] out of bounds {:?}.",
+                index.x, index.y, boundary
+            ),
+        }
+    }
+}
+
+impl<T: Array2dStorageMut, C: Coordinate, I: Into<Vector2<C>>> IndexMut<I>
+    for GenericArray2d<T, C>
+{
+    #[track_caller]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let index: Vector2<C> = index.into();
+        let boundary = self.boundary;
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!(
+                "Index [{:?}, {:?}] out of bounds {:?}.",
+                index.x, index.y, boundary
+            ),
         }
     }
 }
@@ -0,0 +1,185 @@
+//! Multiple arrays sharing the same boundary.
+
+use mint::Vector2;
+
+use crate::{Array2d, Array2dMut, Array2dRef, Boundary, boundary::IntoBoundary};
+
+/// A tuple of [`Array2d`]s that always share the same [`Boundary`].
+///
+/// Geometric operations like `resize`, `insert` and `displace` apply to all layers,
+/// while `get` returns a tuple of references.
+///
+/// Implemented on tuples of 2 to 6 [`Array2d`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layered<L> {
+    layers: L,
+}
+
+/// Tuples of [`Array2d`]s that can be used in [`Layered`].
+pub trait Array2dLayers {
+    /// Returns true if all layers share the same boundary.
+    fn is_aligned(&self) -> bool;
+}
+
+impl<L: Array2dLayers> Layered<L> {
+    /// Combine arrays with the same boundary into layers.
+    ///
+    /// # Panics
+    ///
+    /// If boundaries of the arrays are not equal.
+    #[track_caller]
+    pub fn from_layers(layers: L) -> Self {
+        assert!(layers.is_aligned(), "Boundary mismatch!");
+        Layered { layers }
+    }
+}
+
+impl<L> Layered<L> {
+    /// Returns the layers as a tuple of arrays.
+    pub fn layers(&self) -> &L {
+        &self.layers
+    }
+
+    /// Convert into a tuple of arrays.
+    pub fn into_layers(self) -> L {
+        self.layers
+    }
+}
+
+macro_rules! impl_layered {
+    ($($ty: ident $idx: tt),*) => {
+        impl<$($ty),*> Array2dLayers for ($(Array2d<$ty>,)*) {
+            fn is_aligned(&self) -> bool {
+                let boundary = self.0.boundary();
+                $(self.$idx.boundary() == boundary)&&*
+            }
+        }
+
+        impl<$($ty),*> Default for Layered<($(Array2d<$ty>,)*)> {
+            fn default() -> Self {
+                Layered {
+                    layers: ($(Array2d::<$ty>::default(),)*),
+                }
+            }
+        }
+
+        impl<$($ty),*> Layered<($(Array2d<$ty>,)*)> {
+            /// Create layers with [`Default`] values.
+            pub fn new(boundary: impl IntoBoundary) -> Self
+            where
+                $($ty: Default,)*
+            {
+                let boundary = boundary.into_boundary();
+                Layered {
+                    layers: ($(Array2d::<$ty>::new(boundary),)*),
+                }
+            }
+
+            /// Returns the boundary shared by all layers.
+            pub fn boundary(&self) -> Boundary {
+                self.layers.0.boundary()
+            }
+
+            /// Returns true if contains no items.
+            pub fn is_empty(&self) -> bool {
+                self.boundary().is_empty()
+            }
+
+            /// Returns true if a point is in boundary.
+            pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+                self.boundary().contains(position)
+            }
+
+            /// Returns references to a point in all layers.
+            pub fn get(&self, position: impl Into<Vector2<i32>>) -> Option<($(&$ty,)*)> {
+                let position = position.into();
+                Some(($(self.layers.$idx.get(position)?,)*))
+            }
+
+            /// Returns mutable references to a point in all layers.
+            pub fn get_mut(
+                &mut self,
+                position: impl Into<Vector2<i32>>,
+            ) -> Option<($(&mut $ty,)*)> {
+                let position = position.into();
+                if !self.contains(position) {
+                    return None;
+                }
+                Some(($(self.layers.$idx.get_mut(position)?,)*))
+            }
+
+            /// Try set a position to values, returns `true` if in boundary.
+            pub fn set(&mut self, position: impl Into<Vector2<i32>>, values: ($($ty,)*)) -> bool {
+                let position = position.into();
+                if !self.contains(position) {
+                    return false;
+                }
+                $(self.layers.$idx.set(position, values.$idx);)*
+                true
+            }
+
+            /// Iterate through points and references to values in all layers.
+            pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, ($(&$ty,)*))> {
+                self.boundary()
+                    .iter::<Vector2<i32>>()
+                    .filter_map(|position| Some((position.into(), self.get(position)?)))
+            }
+
+            /// Obtain truncated subslices of all layers.
+            pub fn slice(
+                &self,
+                boundary: impl IntoBoundary,
+            ) -> ($(Array2dRef<'_, $ty>,)*) {
+                let boundary = boundary.into_boundary();
+                ($(self.layers.$idx.slice(boundary),)*)
+            }
+
+            /// Obtain truncated mutable subslices of all layers.
+            pub fn slice_mut(
+                &mut self,
+                boundary: impl IntoBoundary,
+            ) -> ($(Array2dMut<'_, $ty>,)*) {
+                let boundary = boundary.into_boundary();
+                ($(self.layers.$idx.slice_mut(boundary),)*)
+            }
+
+            /// Move the origin point of all layers without affecting underlying data.
+            pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+                let by = by.into();
+                $(self.layers.$idx.displace(by);)*
+            }
+
+            /// Resize all layers and initialize with [`Default::default`].
+            pub fn resize(&mut self, boundary: impl IntoBoundary)
+            where
+                $($ty: Default,)*
+            {
+                let boundary = boundary.into_boundary();
+                $(self.layers.$idx.resize(boundary);)*
+            }
+
+            /// Expand all layers to include a boundary.
+            pub fn resize_containing(&mut self, boundary: Boundary)
+            where
+                $($ty: Default,)*
+            {
+                $(self.layers.$idx.resize_containing(boundary);)*
+            }
+
+            /// Insert a point into all layers and potentially expanding the size with [`Default`] values.
+            pub fn insert(&mut self, position: impl Into<Vector2<i32>>, values: ($($ty,)*))
+            where
+                $($ty: Default,)*
+            {
+                let position = position.into();
+                $(self.layers.$idx.insert(position, values.$idx);)*
+            }
+        }
+    };
+}
+
+impl_layered!(A 0, B 1);
+impl_layered!(A 0, B 1, C 2);
+impl_layered!(A 0, B 1, C 2, D 3);
+impl_layered!(A 0, B 1, C 2, D 3, E 4);
+impl_layered!(A 0, B 1, C 2, D 3, E 4, F 5);
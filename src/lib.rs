@@ -1,42 +1,89 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
+extern crate alloc;
+
+mod array3d;
 mod boundary;
+mod boundary3;
+mod column;
+mod coord;
+mod entry;
+mod error;
+mod fixed;
 mod impls;
 mod index;
+mod layered;
 mod map;
+mod mask;
+mod ops;
+mod quadtree;
+mod reduce;
 mod resize;
+mod rle;
+mod scrolling;
+mod shared;
 mod storage;
+mod tiled;
 mod util;
 mod zip;
-use std::fmt::Debug;
+use alloc::vec::Vec;
+use core::fmt::Debug;
 #[cfg(feature = "serde")]
 mod serde;
 
-pub use boundary::Boundary;
+pub use array3d::{Array3d, Array3dMut, Array3dRef, GenericArray3d};
 use boundary::IntoBoundary;
-use storage::{Array2dStorage, Array2dStorageOwned};
-pub use zip::Zip;
+pub use boundary::{Anchor, Boundary, GenericBoundary};
+pub use boundary3::Boundary3;
+pub use column::{
+    ColumnMajorArray2d, ColumnMajorArray2dMut, ColumnMajorArray2dRef, GenericColumnMajorArray2d,
+};
+use coord::Coordinate;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use error::Array2dError;
+pub use fixed::FixedArray2d;
+pub use layered::Layered;
+pub use mask::Mask2d;
+pub use quadtree::QuadTree2d;
+pub use rle::RleArray2d;
+pub use scrolling::ScrollingArray2d;
+pub use shared::SharedArray2d;
+use storage::{Array2dStorage, Array2dStorageOwned, Array2dStorageRef};
+pub use tiled::TiledArray2d;
+pub use zip::{RowIter, Zip, Zip3, Zip4};
 
 pub mod traits {
     //! Lesser used traits.
     pub use crate::boundary::IntoBoundary;
-    pub use crate::index::Array2dIndexing;
-    pub use crate::storage::{Array2dStorage, Array2dStorageMut, Array2dStorageOwned};
+    pub use crate::boundary3::IntoBoundary3;
+    pub use crate::coord::Coordinate;
+    pub use crate::index::{Array2dIndexing, Array2dIndexingMut};
+    pub use crate::layered::Array2dLayers;
+    pub use crate::ops::Scalar;
+    pub use crate::storage::{
+        Array2dStorage, Array2dStorageMut, Array2dStorageOwned, Array2dStorageRef,
+        Array2dStorageSet, Array2dStorageSlice, Array2dStorageSliceMut,
+    };
     pub use crate::zip::GenericArray2dRef;
 }
 
-/// A 2d array with generic backing storage.
+/// A 2d array with generic backing storage and coordinate type.
+///
+/// The coordinate type defaults to `i32`, see [`Coordinate`].
 #[derive(Clone, Copy)]
-pub struct GenericArray2d<S: Array2dStorage> {
+pub struct GenericArray2d<S: Array2dStorage, C: Coordinate = i32> {
     data: S,
-    boundary: Boundary,
+    boundary: GenericBoundary<C>,
     pitch: usize,
+    /// Index of `boundary.min` in `data`.
+    offset: usize,
 }
 
-impl<S: Array2dStorage<Item: Debug>> Debug for GenericArray2d<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<S: Array2dStorageRef<Item: Debug>, C: Coordinate> Debug for GenericArray2d<S, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("GenericArray2d")
             .field("boundary", &self.boundary)
-            .field("data", &self.data.slice())
+            .field("data", &self.values().collect::<Vec<_>>())
             .field("pitch", &self.pitch)
             .finish()
     }
@@ -51,38 +98,41 @@ pub type Array2dRef<'t, T> = GenericArray2d<&'t [T]>;
 /// A mutable 2d array backed by a mutable slice or a mutable view to an existing 2d array.
 pub type Array2dMut<'t, T> = GenericArray2d<&'t mut [T]>;
 
-impl<T> Default for Array2dRef<'_, T> {
+impl<T, C: Coordinate> Default for GenericArray2d<&[T], C> {
     fn default() -> Self {
-        Array2dRef {
+        GenericArray2d {
             data: &[],
-            boundary: Boundary::EMPTY,
+            boundary: GenericBoundary::EMPTY,
             pitch: 0,
+            offset: 0,
         }
     }
 }
 
-impl<T> Default for Array2dMut<'_, T> {
+impl<T, C: Coordinate> Default for GenericArray2d<&mut [T], C> {
     fn default() -> Self {
-        Array2dMut {
+        GenericArray2d {
             data: &mut [],
-            boundary: Boundary::EMPTY,
+            boundary: GenericBoundary::EMPTY,
             pitch: 0,
+            offset: 0,
         }
     }
 }
 
-impl<T: Default + Array2dStorageOwned> Default for GenericArray2d<T> {
+impl<T: Default + Array2dStorageOwned, C: Coordinate> Default for GenericArray2d<T, C> {
     fn default() -> Self {
         GenericArray2d {
             data: Default::default(),
-            boundary: Boundary::EMPTY,
+            boundary: GenericBoundary::EMPTY,
             pitch: 0,
+            offset: 0,
         }
     }
 }
 
-impl<S: Array2dStorage> IntoBoundary for &GenericArray2d<S> {
-    fn into_boundary(self) -> Boundary {
+impl<S: Array2dStorage, C: Coordinate> IntoBoundary<C> for &GenericArray2d<S, C> {
+    fn into_boundary(self) -> GenericBoundary<C> {
         self.boundary
     }
 }
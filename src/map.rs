@@ -1,77 +1,184 @@
+use alloc::vec::Vec;
 use mint::Vector2;
 
 use crate::Zip;
-use crate::traits::{Array2dStorageMut, Array2dStorageOwned};
+use crate::traits::{
+    Array2dStorageMut, Array2dStorageOwned, Array2dStorageSlice, Array2dStorageSliceMut,
+};
 
-use crate::{Array2d, GenericArray2d, traits::Array2dStorage, zip::GenericArray2dRef};
+use crate::{
+    Coordinate, GenericArray2d, GenericBoundary, traits::Array2dStorageRef, zip::GenericArray2dRef,
+};
 
-impl<T: Array2dStorage> GenericArray2d<T> {
+type Slice<'t, T, C> = GenericArray2d<&'t [T], C>;
+type SliceMut<'t, T, C> = GenericArray2d<&'t mut [T], C>;
+
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2d<T, C> {
     /// Copy the array or slice into an owned array.
-    pub fn copied(&self) -> Array2d<T::Item>
+    pub fn copied(&self) -> GenericArray2d<Vec<T::Item>, C>
     where
         T::Item: Copy,
     {
         let mut data = Vec::with_capacity(self.len());
         data.extend(self.values().copied());
-        Array2d {
+        GenericArray2d {
             data,
             boundary: self.boundary,
-            pitch: self.pitch,
+            pitch: self.boundary.pitch(),
+            offset: 0,
         }
     }
 
     /// Clone the array or slice into an owned array.
-    pub fn cloned(&self) -> Array2d<T::Item>
+    pub fn cloned(&self) -> GenericArray2d<Vec<T::Item>, C>
     where
         T::Item: Clone,
     {
         let mut data = Vec::with_capacity(self.len());
         data.extend(self.values().cloned());
-        Array2d {
+        GenericArray2d {
             data,
             boundary: self.boundary,
-            pitch: self.pitch,
+            pitch: self.boundary.pitch(),
+            offset: 0,
         }
     }
 
     /// Map the array or slice into an owned array.
-    pub fn mapped<U>(&self, f: impl FnMut(&T::Item) -> U) -> Array2d<U> {
+    pub fn mapped<U>(&self, f: impl FnMut(&T::Item) -> U) -> GenericArray2d<Vec<U>, C> {
         let mut data = Vec::with_capacity(self.len());
         data.extend(self.values().map(f));
-        Array2d {
+        GenericArray2d {
             data,
             boundary: self.boundary,
-            pitch: self.pitch,
+            pitch: self.boundary.pitch(),
+            offset: 0,
         }
     }
 
+    /// Iterate through the union of both boundaries by world position,
+    /// yielding `None` for points not contained in an array.
+    pub fn zip_union<'t, U: Array2dStorageRef, P: From<Vector2<C>>>(
+        &'t self,
+        rhs: &'t GenericArray2d<U, C>,
+    ) -> impl Iterator<Item = (P, Option<&'t T::Item>, Option<&'t U::Item>)> {
+        self.boundary
+            .union(rhs.boundary)
+            .iter::<Vector2<C>>()
+            .map(|pos| (pos.into(), self.get(pos), rhs.get(pos)))
+    }
+}
+
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2d<T, C> {
     /// Combine with another array, must have the same dimension.
     ///
     /// Supports both mutable and immutable references.
     pub fn zip<U: GenericArray2dRef>(&self, rhs: U) -> Zip<&Self, U> {
         Zip(self, rhs)
     }
+}
+
+impl<T: Array2dStorageSlice, C: Coordinate> GenericArray2d<T, C> {
+    /// Combine with another array by world position,
+    /// only the intersection of both boundaries is visited.
+    pub fn zip_aligned<'t, U: Array2dStorageSlice>(
+        &'t self,
+        rhs: &'t GenericArray2d<U, C>,
+    ) -> Zip<Slice<'t, T::Item, C>, Slice<'t, U::Item, C>> {
+        let intersection = self.boundary.intersection(rhs.boundary).unwrap_or_default();
+        Zip(self.slice(intersection), rhs.slice(intersection))
+    }
+
+    /// Combine with another array by world position,
+    /// only the intersection of both boundaries is visited.
+    pub fn zip_aligned_mut<'t, U: Array2dStorageSlice>(
+        &'t mut self,
+        rhs: &'t GenericArray2d<U, C>,
+    ) -> Zip<SliceMut<'t, T::Item, C>, Slice<'t, U::Item, C>>
+    where
+        T: Array2dStorageSliceMut,
+    {
+        let intersection = self.boundary.intersection(rhs.boundary).unwrap_or_default();
+        Zip(self.slice_mut(intersection), rhs.slice(intersection))
+    }
 
     /// Combine with another array, must have the same dimension.
     ///
     /// Supports both mutable and immutable references.
     pub fn zip_mut<U: GenericArray2dRef>(&mut self, rhs: U) -> Zip<&mut Self, U>
     where
-        T: Array2dStorageMut,
+        T: Array2dStorageSliceMut,
     {
         Zip(self, rhs)
     }
 }
 
-impl<T: Array2dStorage<Item = bool>> GenericArray2d<T> {
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the smallest boundary containing all non-empty values,
+    /// returns [`GenericBoundary::EMPTY`] if all values are empty.
+    pub fn content_boundary(
+        &self,
+        mut is_empty: impl FnMut(&T::Item) -> bool,
+    ) -> GenericBoundary<C> {
+        GenericBoundary::from_iter(
+            self.iter::<Vector2<C>>()
+                .filter_map(|(pos, value)| (!is_empty(value)).then_some(pos)),
+        )
+    }
+
+    /// Shrink the array to the smallest boundary containing all non-empty values.
+    pub fn trim(&mut self, is_empty: impl FnMut(&T::Item) -> bool)
+    where
+        T: Array2dStorageOwned,
+    {
+        let boundary = self.content_boundary(is_empty);
+        if boundary == self.boundary {
+            return;
+        }
+        let array = core::mem::replace(
+            self,
+            GenericArray2d {
+                data: T::from_vec(Vec::new()),
+                boundary: GenericBoundary::EMPTY,
+                pitch: 0,
+                offset: 0,
+            },
+        );
+        let data = array
+            .iter_owned::<Vector2<C>>()
+            .filter_map(|(pos, value)| boundary.contains(pos).then_some(value))
+            .collect();
+        *self = GenericArray2d {
+            data: T::from_vec(data),
+            boundary,
+            pitch: boundary.pitch(),
+            offset: 0,
+        };
+    }
+}
+
+impl<T: Array2dStorageRef<Item = bool>, C: Coordinate> GenericArray2d<T, C> {
     /// For a boolean 2d array, iterate through points with `true` values.
-    pub fn iter_points<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = U> {
+    pub fn iter_points<U: From<Vector2<C>>>(&self) -> impl Iterator<Item = U> {
         self.iter::<U>()
             .filter_map(|(pos, is_true)| is_true.then_some(pos))
     }
 
+    /// For a boolean 2d array, returns the smallest boundary containing all `true` values.
+    pub fn points_boundary(&self) -> GenericBoundary<C> {
+        self.content_boundary(|x| !x)
+    }
+
+    /// For a boolean 2d array, shrink the array to the smallest boundary containing all `true` values.
+    pub fn trim_points(&mut self)
+    where
+        T: Array2dStorageOwned,
+    {
+        self.trim(|x| !x)
+    }
+
     /// For a boolean 2d array, iterate through points with `true` values.
-    pub fn iter_points_owned<U: From<Vector2<i32>>>(self) -> impl Iterator<Item = U>
+    pub fn iter_points_owned<U: From<Vector2<C>>>(self) -> impl Iterator<Item = U>
     where
         T: Array2dStorageOwned,
     {
@@ -80,9 +187,9 @@ impl<T: Array2dStorage<Item = bool>> GenericArray2d<T> {
     }
 }
 
-impl<T: Array2dStorage<Item = Option<A>>, A> GenericArray2d<T> {
+impl<T: Array2dStorageRef<Item = Option<A>>, A, C: Coordinate> GenericArray2d<T, C> {
     /// For a option 2d array, iterate through points with `Some` values.
-    pub fn iter_some<'t, U: From<Vector2<i32>>>(&'t self) -> impl Iterator<Item = (U, &'t A)>
+    pub fn iter_some<'t, U: From<Vector2<C>>>(&'t self) -> impl Iterator<Item = (U, &'t A)>
     where
         A: 't,
     {
@@ -91,7 +198,7 @@ impl<T: Array2dStorage<Item = Option<A>>, A> GenericArray2d<T> {
     }
 
     /// For a option 2d array, iterate through points with `Some` values.
-    pub fn iter_some_mut<'t, U: From<Vector2<i32>>>(
+    pub fn iter_some_mut<'t, U: From<Vector2<C>>>(
         &'t mut self,
     ) -> impl Iterator<Item = (U, &'t mut A)>
     where
@@ -102,8 +209,21 @@ impl<T: Array2dStorage<Item = Option<A>>, A> GenericArray2d<T> {
             .filter_map(|(pos, value)| value.as_mut().map(|v| (pos, v)))
     }
 
-    /// For a boolean 2d array, iterate through points with `Some` values.
-    pub fn iter_some_owned<U: From<Vector2<i32>>>(self) -> impl Iterator<Item = (U, A)>
+    /// For a option 2d array, returns the smallest boundary containing all `Some` values.
+    pub fn some_boundary(&self) -> GenericBoundary<C> {
+        self.content_boundary(Option::is_none)
+    }
+
+    /// For a option 2d array, shrink the array to the smallest boundary containing all `Some` values.
+    pub fn trim_some(&mut self)
+    where
+        T: Array2dStorageOwned,
+    {
+        self.trim(Option::is_none)
+    }
+
+    /// For a option 2d array, iterate through points with `Some` values.
+    pub fn iter_some_owned<U: From<Vector2<C>>>(self) -> impl Iterator<Item = (U, A)>
     where
         T: Array2dStorageOwned,
     {
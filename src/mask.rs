@@ -0,0 +1,442 @@
+//! Bit-packed boolean masks.
+
+use alloc::{vec, vec::Vec};
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+use mint::Vector2;
+
+use crate::{
+    Array2d, Boundary, GenericArray2d,
+    boundary::IntoBoundary,
+    storage::{Array2dStorage, Array2dStorageRef, Array2dStorageSet},
+    util::*,
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A 2d boolean mask that stores one bit per point.
+///
+/// Each row starts at a new word, bits outside of the boundary are always `0`.
+/// Set operations align masks by world position,
+/// [`as_array`](Mask2d::as_array) exposes the mask to generic array methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask2d {
+    words: Vec<u64>,
+    boundary: Boundary,
+    /// Number of words in a row.
+    pitch: usize,
+}
+
+impl Default for Mask2d {
+    fn default() -> Self {
+        Mask2d {
+            words: Vec::new(),
+            boundary: Boundary::EMPTY,
+            pitch: 0,
+        }
+    }
+}
+
+/// Returns 64 bits of a row starting from a bit index, bits outside of the row are `0`.
+fn extract(row: &[u64], start: i64) -> u64 {
+    let word = start.div_euclid(BITS as i64);
+    let shift = start.rem_euclid(BITS as i64) as u32;
+    let get = |i: i64| {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| row.get(i))
+            .copied()
+            .unwrap_or(0)
+    };
+    let lo = get(word) >> shift;
+    let hi = if shift == 0 {
+        0
+    } else {
+        get(word + 1) << (BITS as u32 - shift)
+    };
+    lo | hi
+}
+
+impl Mask2d {
+    /// Create a mask with all points unset.
+    pub fn new(boundary: impl IntoBoundary) -> Self {
+        let boundary = boundary.into_boundary();
+        let pitch = boundary.pitch().div_ceil(BITS);
+        Mask2d {
+            words: vec![0; pitch * boundary.dimension.y as usize],
+            boundary,
+            pitch,
+        }
+    }
+
+    /// Create a mask with all points set.
+    pub fn new_filled(boundary: impl IntoBoundary) -> Self {
+        let mut result = Self::new(boundary);
+        result.fill(true);
+        result
+    }
+
+    /// Initialize a mask with a function.
+    pub fn init<U: From<Vector2<i32>>>(
+        boundary: impl IntoBoundary,
+        mut init: impl FnMut(U) -> bool,
+    ) -> Self {
+        let mut result = Self::new(boundary);
+        for position in result.boundary.iter::<Vector2<i32>>() {
+            if init(position.into()) {
+                result.set(position, true);
+            }
+        }
+        result
+    }
+
+    /// Create a mask from a list of points, the boundary is the smallest boundary containing all points.
+    pub fn from_points<U: Into<Vector2<i32>>>(points: impl IntoIterator<Item = U> + Clone) -> Self {
+        let boundary = Boundary::from_iter(points.clone());
+        let mut result = Self::new(boundary);
+        for point in points {
+            result.set(point, true);
+        }
+        result
+    }
+
+    /// Returns true if contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.boundary.is_empty()
+    }
+
+    /// Returns the number of points in the boundary.
+    pub fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    /// Returns the boundary of the mask.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.boundary.contains(position)
+    }
+
+    fn bit_of(&self, position: Vector2<i32>) -> Option<(usize, u64)> {
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        let local = sub(position, self.boundary.min);
+        let (x, y) = (local.x as usize, local.y as usize);
+        Some((y * self.pitch + x / BITS, 1 << (x % BITS)))
+    }
+
+    /// Returns the value of a point, returns `false` if out of bounds.
+    pub fn get(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.bit_of(position.into())
+            .is_some_and(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, position: impl Into<Vector2<i32>>, value: bool) -> bool {
+        let Some((word, bit)) = self.bit_of(position.into()) else {
+            return false;
+        };
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+        true
+    }
+
+    /// Set all points in the mask.
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+        if value {
+            self.clear_padding();
+        }
+    }
+
+    /// Ensure bits outside of the boundary are `0`.
+    fn clear_padding(&mut self) {
+        let rem = self.boundary.pitch() % BITS;
+        if rem == 0 || self.pitch == 0 {
+            return;
+        }
+        let mask = (1u64 << rem) - 1;
+        for row in self.words.chunks_mut(self.pitch) {
+            if let Some(last) = row.last_mut() {
+                *last &= mask;
+            }
+        }
+    }
+
+    /// Returns the words of a row in world coordinates, `y` must be in bounds.
+    fn row(&self, y: i32) -> &[u64] {
+        let start = (y - self.boundary.min.y) as usize * self.pitch;
+        &self.words[start..start + self.pitch]
+    }
+
+    /// Returns 64 bits of a row starting from world position `x`,
+    /// points outside of the boundary are `0`.
+    fn word_at(&self, x: i32, y: i32) -> u64 {
+        if self.is_empty() || y < self.boundary.min.y || y > self.boundary.max().y {
+            return 0;
+        }
+        extract(self.row(y), x as i64 - self.boundary.min.x as i64)
+    }
+
+    /// Returns the number of set points.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns the number of unset points.
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+
+    /// Returns true if any point is set.
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|w| *w != 0)
+    }
+
+    /// Iterate through pairs of points and values in the mask.
+    pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, bool)> {
+        self.boundary
+            .iter::<Vector2<i32>>()
+            .map(|p| (p.into(), self.get(p)))
+    }
+
+    /// Iterate through set points in the mask.
+    pub fn iter_points<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = U> {
+        let (min, pitch) = (self.boundary.min, self.pitch.max(1));
+        self.words.iter().enumerate().flat_map(move |(i, word)| {
+            let mut word = *word;
+            let x = ((i % pitch) * BITS) as i32;
+            let y = (i / pitch) as i32;
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as i32;
+                word &= word - 1;
+                Some(add(min, Vector2 { x: x + bit, y }).into())
+            })
+        })
+    }
+
+    /// Returns the smallest boundary containing all set points,
+    /// returns [`Boundary::EMPTY`] if no points are set.
+    pub fn points_boundary(&self) -> Boundary {
+        Boundary::from_iter(self.iter_points::<Vector2<i32>>())
+    }
+
+    /// Move the origin point of the mask without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+        self.boundary.displace(by);
+    }
+
+    /// Create a mask with a new boundary, points outside of the mask are unset.
+    pub fn resized(&self, boundary: impl IntoBoundary) -> Self {
+        self.combine(self, boundary.into_boundary(), |a, _| a)
+    }
+
+    /// Combine two masks by world position word by word.
+    fn combine(&self, other: &Mask2d, boundary: Boundary, op: impl Fn(u64, u64) -> u64) -> Self {
+        let mut result = Mask2d::new(boundary);
+        let min = boundary.min;
+        for (y, row) in result.words.chunks_mut(result.pitch.max(1)).enumerate() {
+            let y = min.y.wrapping_add(y as i32);
+            for (i, word) in row.iter_mut().enumerate() {
+                let x = min.x.wrapping_add((i * BITS) as i32);
+                *word = op(self.word_at(x, y), other.word_at(x, y));
+            }
+        }
+        result.clear_padding();
+        result
+    }
+
+    /// View the mask as a [`GenericArray2d`] of `bool`,
+    /// which enables generic methods like [`zip`](GenericArray2d::zip) or [`count`](GenericArray2d::count).
+    pub fn as_array(&self) -> GenericArray2d<&Mask2d> {
+        GenericArray2d {
+            data: self,
+            boundary: self.boundary,
+            pitch: self.pitch * BITS,
+            offset: 0,
+        }
+    }
+
+    /// View the mask as a mutable [`GenericArray2d`] of `bool`.
+    pub fn as_array_mut(&mut self) -> GenericArray2d<&mut Mask2d> {
+        GenericArray2d {
+            boundary: self.boundary,
+            pitch: self.pitch * BITS,
+            offset: 0,
+            data: self,
+        }
+    }
+
+    /// Returns a bit by its offset in the storage.
+    fn bit(&self, index: usize) -> Option<&'static bool> {
+        let word = self.words.get(index / BITS)?;
+        Some(if word & (1 << (index % BITS)) != 0 {
+            &true
+        } else {
+            &false
+        })
+    }
+
+    /// Set a bit by its offset in the storage.
+    fn set_bit(&mut self, index: usize, value: bool) -> bool {
+        let Some(word) = self.words.get_mut(index / BITS) else {
+            return false;
+        };
+        if value {
+            *word |= 1 << (index % BITS);
+        } else {
+            *word &= !(1 << (index % BITS));
+        }
+        true
+    }
+
+    /// Convert into an [`Array2d<bool>`].
+    pub fn to_array(&self) -> Array2d<bool> {
+        Array2d::init(self.boundary, |p: Vector2<i32>| self.get(p))
+    }
+}
+
+impl Array2dStorage for &Mask2d {
+    type Item = bool;
+
+    fn len(&self) -> usize {
+        self.words.len() * BITS
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<bool> {
+        self.bit(index).copied()
+    }
+}
+
+/// Bits are not addressable, references point to constants.
+impl Array2dStorageRef for &Mask2d {
+    fn get(&self, index: usize) -> Option<&bool> {
+        self.bit(index)
+    }
+}
+
+impl Array2dStorage for &mut Mask2d {
+    type Item = bool;
+
+    fn len(&self) -> usize {
+        self.words.len() * BITS
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<bool> {
+        self.bit(index).copied()
+    }
+}
+
+impl Array2dStorageRef for &mut Mask2d {
+    fn get(&self, index: usize) -> Option<&bool> {
+        self.bit(index)
+    }
+}
+
+impl Array2dStorageSet for &mut Mask2d {
+    fn set(&mut self, index: usize, value: bool) -> bool {
+        self.set_bit(index, value)
+    }
+}
+
+impl<T: Array2dStorageRef<Item = bool>> From<&GenericArray2d<T>> for Mask2d {
+    fn from(value: &GenericArray2d<T>) -> Self {
+        let mut result = Mask2d::new(value.boundary());
+        for (position, item) in value.iter::<Vector2<i32>>() {
+            if *item {
+                result.set(position, true);
+            }
+        }
+        result
+    }
+}
+
+impl From<&Mask2d> for Array2d<bool> {
+    fn from(value: &Mask2d) -> Self {
+        value.to_array()
+    }
+}
+
+impl BitAnd for &Mask2d {
+    type Output = Mask2d;
+
+    /// Returns points set in both masks, the boundary is the intersection of both boundaries.
+    fn bitand(self, rhs: Self) -> Mask2d {
+        let boundary = self.boundary.intersection(rhs.boundary).unwrap_or_default();
+        self.combine(rhs, boundary, |a, b| a & b)
+    }
+}
+
+impl BitOr for &Mask2d {
+    type Output = Mask2d;
+
+    /// Returns points set in either mask, the boundary is the union of both boundaries.
+    fn bitor(self, rhs: Self) -> Mask2d {
+        self.combine(rhs, self.boundary.union(rhs.boundary), |a, b| a | b)
+    }
+}
+
+impl BitXor for &Mask2d {
+    type Output = Mask2d;
+
+    /// Returns points set in exactly one mask, the boundary is the union of both boundaries.
+    fn bitxor(self, rhs: Self) -> Mask2d {
+        self.combine(rhs, self.boundary.union(rhs.boundary), |a, b| a ^ b)
+    }
+}
+
+impl Not for &Mask2d {
+    type Output = Mask2d;
+
+    /// Returns points unset in the mask, the boundary is unchanged.
+    fn not(self) -> Mask2d {
+        let mut result = self.clone();
+        for word in &mut result.words {
+            *word = !*word;
+        }
+        result.clear_padding();
+        result
+    }
+}
+
+impl Not for Mask2d {
+    type Output = Mask2d;
+
+    fn not(self) -> Mask2d {
+        !&self
+    }
+}
+
+macro_rules! impl_mask_op {
+    ($op: ident, $f: ident) => {
+        impl $op<&Mask2d> for Mask2d {
+            type Output = Mask2d;
+
+            fn $f(self, rhs: &Mask2d) -> Mask2d {
+                (&self).$f(rhs)
+            }
+        }
+
+        impl $op for Mask2d {
+            type Output = Mask2d;
+
+            fn $f(self, rhs: Mask2d) -> Mask2d {
+                (&self).$f(&rhs)
+            }
+        }
+    };
+}
+
+impl_mask_op!(BitAnd, bitand);
+impl_mask_op!(BitOr, bitor);
+impl_mask_op!(BitXor, bitxor);
@@ -0,0 +1,167 @@
+//! Element-wise arithmetic operators.
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use mint::Vector2;
+
+use crate::{
+    Coordinate, GenericArray2d, Zip,
+    storage::{Array2dStorageMut, Array2dStorageRef},
+};
+
+/// Types that can be used as the scalar operand of arithmetic operators on [`GenericArray2d`].
+///
+/// Implemented on primitive numbers. Other types, including newtypes over numbers,
+/// must opt in with `impl Scalar for MyType {}` to enable `array + scalar`,
+/// a blanket implementation would overlap with the `array + &array` operators.
+pub trait Scalar: Clone {}
+
+macro_rules! impl_scalar {
+    ($($ty: ty),*) => {
+        $(impl Scalar for $ty {})*
+    };
+}
+
+impl_scalar!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+macro_rules! impl_op {
+    ($op: ident, $f: ident, $op_assign: ident, $f_assign: ident) => {
+        impl<A: Array2dStorageRef, B: Array2dStorageRef, C: Coordinate> $op<&GenericArray2d<B, C>>
+            for &GenericArray2d<A, C>
+        where
+            A::Item: Clone + $op<B::Item>,
+            B::Item: Clone,
+        {
+            type Output = GenericArray2d<Vec<<A::Item as $op<B::Item>>::Output>, C>;
+
+            #[track_caller]
+            fn $f(self, rhs: &GenericArray2d<B, C>) -> Self::Output {
+                Zip(self, rhs).map(|a, b| a.clone().$f(b.clone()))
+            }
+        }
+
+        impl<A: Array2dStorageMut, B: Array2dStorageRef, C: Coordinate> $op<&GenericArray2d<B, C>>
+            for GenericArray2d<A, C>
+        where
+            A::Item: Clone + $op<B::Item, Output = A::Item>,
+            B::Item: Clone,
+        {
+            type Output = GenericArray2d<A, C>;
+
+            #[track_caller]
+            fn $f(mut self, rhs: &GenericArray2d<B, C>) -> Self::Output {
+                assert!(
+                    self.boundary.dimension == rhs.boundary.dimension,
+                    "Dimension mismatch!"
+                );
+                for (a, b) in self.values_mut().zip(rhs.values()) {
+                    *a = a.clone().$f(b.clone());
+                }
+                self
+            }
+        }
+
+        impl<A: Array2dStorageRef, S: Scalar, C: Coordinate> $op<S> for &GenericArray2d<A, C>
+        where
+            A::Item: Clone + $op<S>,
+        {
+            type Output = GenericArray2d<Vec<<A::Item as $op<S>>::Output>, C>;
+
+            fn $f(self, rhs: S) -> Self::Output {
+                self.mapped(|a| a.clone().$f(rhs.clone()))
+            }
+        }
+
+        impl<A: Array2dStorageMut, S: Scalar, C: Coordinate> $op<S> for GenericArray2d<A, C>
+        where
+            A::Item: Clone + $op<S, Output = A::Item>,
+        {
+            type Output = GenericArray2d<A, C>;
+
+            fn $f(mut self, rhs: S) -> Self::Output {
+                for item in self.values_mut() {
+                    *item = item.clone().$f(rhs.clone());
+                }
+                self
+            }
+        }
+
+        impl<A: Array2dStorageMut, B: Array2dStorageRef, C: Coordinate>
+            $op_assign<&GenericArray2d<B, C>> for GenericArray2d<A, C>
+        where
+            A::Item: $op_assign<B::Item>,
+            B::Item: Clone,
+        {
+            #[track_caller]
+            fn $f_assign(&mut self, rhs: &GenericArray2d<B, C>) {
+                assert!(
+                    self.boundary.dimension == rhs.boundary.dimension,
+                    "Dimension mismatch!"
+                );
+                for (a, b) in self.values_mut().zip(rhs.values()) {
+                    a.$f_assign(b.clone());
+                }
+            }
+        }
+
+        impl<A: Array2dStorageMut, S: Scalar, C: Coordinate> $op_assign<S> for GenericArray2d<A, C>
+        where
+            A::Item: $op_assign<S>,
+        {
+            fn $f_assign(&mut self, rhs: S) {
+                for item in self.values_mut() {
+                    item.$f_assign(rhs.clone());
+                }
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign);
+impl_op!(Sub, sub, SubAssign, sub_assign);
+impl_op!(Mul, mul, MulAssign, mul_assign);
+impl_op!(Div, div, DivAssign, div_assign);
+
+impl<A: Array2dStorageRef, C: Coordinate> Neg for &GenericArray2d<A, C>
+where
+    A::Item: Clone + Neg,
+{
+    type Output = GenericArray2d<Vec<<A::Item as Neg>::Output>, C>;
+
+    fn neg(self) -> Self::Output {
+        self.mapped(|a| -a.clone())
+    }
+}
+
+impl<A: Array2dStorageMut, C: Coordinate> Neg for GenericArray2d<A, C>
+where
+    A::Item: Clone + Neg<Output = A::Item>,
+{
+    type Output = GenericArray2d<A, C>;
+
+    fn neg(mut self) -> Self::Output {
+        for item in self.values_mut() {
+            *item = -item.clone();
+        }
+        self
+    }
+}
+
+impl<T: Array2dStorageMut, C: Coordinate> GenericArray2d<T, C> {
+    /// Add another array to this array at an offset,
+    /// only the overlapping region is modified.
+    ///
+    /// Equivalent to `self.paint(other, at, |a, b| *a += b.clone())`.
+    pub fn add_at<U: Clone>(
+        &mut self,
+        other: &GenericArray2d<impl Array2dStorageRef<Item = U>, C>,
+        at: impl Into<Vector2<C>>,
+    ) where
+        T::Item: AddAssign<U>,
+    {
+        self.paint(other, at, |a, b| *a += b.clone());
+    }
+}
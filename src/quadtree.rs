@@ -0,0 +1,352 @@
+//! Region quadtree that collapses uniform squares.
+
+use alloc::{boxed::Box, vec::Vec};
+use mint::Vector2;
+
+use crate::{
+    Array2d, Boundary, GenericArray2d, boundary::IntoBoundary, storage::Array2dStorageRef,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node<T> {
+    Leaf(T),
+    /// Children in the order `[-x -y]`, `[+x -y]`, `[-x +y]`, `[+x +y]`.
+    Branch(Box<[Node<T>; 4]>),
+}
+
+/// A square in world space, uses `i64` to avoid overflow near the edge of `i32`.
+#[derive(Debug, Clone, Copy)]
+struct Square {
+    x: i64,
+    y: i64,
+    size: i64,
+}
+
+impl Square {
+    fn child(self, index: usize) -> Square {
+        let size = self.size / 2;
+        Square {
+            x: self.x + (index & 1) as i64 * size,
+            y: self.y + (index >> 1) as i64 * size,
+            size,
+        }
+    }
+
+    /// Returns the part of the square inside a boundary.
+    fn clip(self, boundary: Boundary) -> Option<Boundary> {
+        let x0 = self.x.max(boundary.min.x as i64);
+        let y0 = self.y.max(boundary.min.y as i64);
+        let x1 = (self.x + self.size).min(boundary.min.x as i64 + boundary.dimension.x as i64);
+        let y1 = (self.y + self.size).min(boundary.min.y as i64 + boundary.dimension.y as i64);
+        if x0 >= x1 || y0 >= y1 {
+            return None;
+        }
+        Some(Boundary {
+            min: Vector2 {
+                x: x0 as i32,
+                y: y0 as i32,
+            },
+            dimension: Vector2 {
+                x: (x1 - x0) as u32,
+                y: (y1 - y0) as u32,
+            },
+        })
+    }
+}
+
+/// A 2d array stored as a region quadtree, uniform squares are collapsed into a single leaf.
+///
+/// The tree covers the smallest power of two square containing the boundary,
+/// points outside of the boundary are ignored when collapsing and comparing.
+#[derive(Debug, Clone)]
+pub struct QuadTree2d<T> {
+    root: Option<Node<T>>,
+    boundary: Boundary,
+}
+
+impl<T> Default for QuadTree2d<T> {
+    fn default() -> Self {
+        QuadTree2d {
+            root: None,
+            boundary: Boundary::EMPTY,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for QuadTree2d<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.boundary == other.boundary
+            && match (&self.root, &other.root) {
+                (Some(a), Some(b)) => node_eq(a, b, self.square(), self.boundary),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+}
+
+impl<T: Eq> Eq for QuadTree2d<T> {}
+
+impl<T> QuadTree2d<T> {
+    /// Initialize with a single value.
+    pub fn new_filled(boundary: impl IntoBoundary, value: T) -> Self {
+        let boundary = boundary.into_boundary();
+        QuadTree2d {
+            root: (!boundary.is_empty()).then_some(Node::Leaf(value)),
+            boundary,
+        }
+    }
+
+    /// Initialize with [`Default::default`].
+    pub fn new(boundary: impl IntoBoundary) -> Self
+    where
+        T: Default,
+    {
+        Self::new_filled(boundary, T::default())
+    }
+
+    /// Returns true if contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.boundary.is_empty()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    /// Returns the boundary of the array.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.boundary.contains(position)
+    }
+
+    fn square(&self) -> Square {
+        let dimension = self.boundary.dimension;
+        Square {
+            x: self.boundary.min.x as i64,
+            y: self.boundary.min.y as i64,
+            size: (dimension.x.max(dimension.y) as u64).next_power_of_two() as i64,
+        }
+    }
+
+    /// Returns a point.
+    pub fn get(&self, position: impl Into<Vector2<i32>>) -> Option<&T> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        let (x, y) = (position.x as i64, position.y as i64);
+        let mut square = self.square();
+        let mut node = self.root.as_ref()?;
+        loop {
+            match node {
+                Node::Leaf(value) => return Some(value),
+                Node::Branch(children) => {
+                    let half = square.size / 2;
+                    let index =
+                        usize::from(x >= square.x + half) | usize::from(y >= square.y + half) << 1;
+                    square = square.child(index);
+                    node = &children[index];
+                }
+            }
+        }
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, position: impl Into<Vector2<i32>>, value: T) -> bool
+    where
+        T: Clone + PartialEq,
+    {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return false;
+        }
+        self.fill(Boundary::from_point(position), value);
+        true
+    }
+
+    /// Set all points in a region to a value, points outside of the boundary are ignored.
+    ///
+    /// Only squares crossing the edge of the region are subdivided.
+    pub fn fill(&mut self, region: impl IntoBoundary, value: T)
+    where
+        T: Clone + PartialEq,
+    {
+        let Some(region) = self
+            .boundary
+            .intersection(region.into_boundary())
+            .filter(|region| !region.is_empty())
+        else {
+            return;
+        };
+        let (square, boundary) = (self.square(), self.boundary);
+        if let Some(root) = &mut self.root {
+            fill_node(root, square, boundary, region, &value);
+        }
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub fn leaf_count(&self) -> usize {
+        self.iter_leaves().count()
+    }
+
+    /// Iterate through uniform regions of the array, clipped to the boundary.
+    pub fn iter_leaves(&self) -> impl Iterator<Item = (Boundary, &T)> {
+        let boundary = self.boundary;
+        let mut stack: Vec<_> = self.root.iter().map(|root| (root, self.square())).collect();
+        core::iter::from_fn(move || {
+            while let Some((node, square)) = stack.pop() {
+                match node {
+                    Node::Leaf(value) => {
+                        if let Some(area) = square.clip(boundary) {
+                            return Some((area, value));
+                        }
+                    }
+                    Node::Branch(children) => {
+                        stack.extend(
+                            children
+                                .iter()
+                                .enumerate()
+                                .rev()
+                                .map(|(i, child)| (child, square.child(i))),
+                        );
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+        self.boundary.displace(by);
+    }
+
+    /// Clone into a dense [`Array2d`].
+    pub fn cloned(&self) -> Array2d<T>
+    where
+        T: Clone,
+    {
+        Array2d::init(self.boundary, |p: Vector2<i32>| {
+            self.get(p).expect("Must be in boundary.").clone()
+        })
+    }
+}
+
+fn fill_node<T: Clone + PartialEq>(
+    node: &mut Node<T>,
+    square: Square,
+    boundary: Boundary,
+    region: Boundary,
+    value: &T,
+) {
+    let Some(area) = square.clip(boundary) else {
+        return;
+    };
+    let Some(intersection) = area.intersection(region).filter(|b| !b.is_empty()) else {
+        return;
+    };
+    if intersection == area {
+        *node = Node::Leaf(value.clone());
+        return;
+    }
+    if let Node::Leaf(v) = node {
+        if v == value {
+            return;
+        }
+        let v = v.clone();
+        *node = Node::Branch(Box::new(core::array::from_fn(|_| Node::Leaf(v.clone()))));
+    }
+    if let Node::Branch(children) = node {
+        for (i, child) in children.iter_mut().enumerate() {
+            fill_node(child, square.child(i), boundary, region, value);
+        }
+    }
+    collapse(node, square, boundary);
+}
+
+/// Merge a branch into a leaf if all children inside the boundary are equal leaves.
+fn collapse<T: Clone + PartialEq>(node: &mut Node<T>, square: Square, boundary: Boundary) {
+    let Node::Branch(children) = node else {
+        return;
+    };
+    let mut value = None;
+    for (i, child) in children.iter().enumerate() {
+        if square.child(i).clip(boundary).is_none() {
+            continue;
+        }
+        let Node::Leaf(v) = child else {
+            return;
+        };
+        match value {
+            Some(u) if u != v => return,
+            _ => value = Some(v),
+        }
+    }
+    if let Some(value) = value.cloned() {
+        *node = Node::Leaf(value);
+    }
+}
+
+/// Compare two nodes covering the same square, ignoring points outside of the boundary.
+fn node_eq<T: PartialEq>(a: &Node<T>, b: &Node<T>, square: Square, boundary: Boundary) -> bool {
+    if square.clip(boundary).is_none() {
+        return true;
+    }
+    match (a, b) {
+        (Node::Leaf(a), Node::Leaf(b)) => a == b,
+        (Node::Branch(a), Node::Branch(b)) => {
+            (0..4).all(|i| node_eq(&a[i], &b[i], square.child(i), boundary))
+        }
+        (leaf @ Node::Leaf(_), Node::Branch(children))
+        | (Node::Branch(children), leaf @ Node::Leaf(_)) => children
+            .iter()
+            .enumerate()
+            .all(|(i, child)| node_eq(leaf, child, square.child(i), boundary)),
+    }
+}
+
+fn build<T: Clone + PartialEq>(
+    square: Square,
+    boundary: Boundary,
+    get: &impl Fn(Vector2<i32>) -> T,
+) -> Node<T> {
+    // Squares outside of the boundary are never read, so any value works.
+    if square.size == 1 || square.clip(boundary).is_none() {
+        let position = Vector2 {
+            x: square.x.min(boundary.max().x as i64) as i32,
+            y: square.y.min(boundary.max().y as i64) as i32,
+        };
+        return Node::Leaf(get(position));
+    }
+    let mut node = Node::Branch(Box::new(core::array::from_fn(|i| {
+        build(square.child(i), boundary, get)
+    })));
+    collapse(&mut node, square, boundary);
+    node
+}
+
+impl<S: Array2dStorageRef<Item: Clone + PartialEq>> From<&GenericArray2d<S>>
+    for QuadTree2d<S::Item>
+{
+    fn from(value: &GenericArray2d<S>) -> Self {
+        let mut result = QuadTree2d {
+            root: None,
+            boundary: value.boundary(),
+        };
+        if !result.is_empty() {
+            let get = |p: Vector2<i32>| value.get(p).expect("Must be in boundary.").clone();
+            result.root = Some(build(result.square(), result.boundary, &get));
+        }
+        result
+    }
+}
+
+impl<T: Clone> From<&QuadTree2d<T>> for Array2d<T> {
+    fn from(value: &QuadTree2d<T>) -> Self {
+        value.cloned()
+    }
+}
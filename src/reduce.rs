@@ -0,0 +1,131 @@
+//! Reductions and statistics over arrays.
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::iter::Sum;
+
+use mint::Vector2;
+
+use crate::{Coordinate, GenericArray2d, storage::Array2dStorageRef};
+
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the sum of all values in the array.
+    pub fn sum<'t, U: Sum<&'t T::Item>>(&'t self) -> U {
+        self.values().sum()
+    }
+
+    /// Returns the smallest value in the array, the first one is returned if multiple are equal.
+    ///
+    /// Values that cannot be compared, like `NaN`, are ignored unless they are the first value.
+    pub fn min(&self) -> Option<&T::Item>
+    where
+        T::Item: PartialOrd,
+    {
+        self.values().reduce(|a, b| if b < a { b } else { a })
+    }
+
+    /// Returns the largest value in the array, the first one is returned if multiple are equal.
+    ///
+    /// Values that cannot be compared, like `NaN`, are ignored unless they are the first value.
+    pub fn max(&self) -> Option<&T::Item>
+    where
+        T::Item: PartialOrd,
+    {
+        self.values().reduce(|a, b| if b > a { b } else { a })
+    }
+
+    /// Returns the position of the smallest value in the array,
+    /// the first one is returned if multiple are equal.
+    pub fn argmin<U: From<Vector2<C>>>(&self) -> Option<U>
+    where
+        T::Item: PartialOrd,
+    {
+        self.iter::<Vector2<C>>()
+            .reduce(|a, b| if b.1 < a.1 { b } else { a })
+            .map(|(pos, _)| pos.into())
+    }
+
+    /// Returns the position of the largest value in the array,
+    /// the first one is returned if multiple are equal.
+    pub fn argmax<U: From<Vector2<C>>>(&self) -> Option<U>
+    where
+        T::Item: PartialOrd,
+    {
+        self.iter::<Vector2<C>>()
+            .reduce(|a, b| if b.1 > a.1 { b } else { a })
+            .map(|(pos, _)| pos.into())
+    }
+
+    /// Returns the mean of all values in the array, returns `None` if empty.
+    ///
+    /// Requires [`Into<f64>`], which is not implemented by `i64`, `u64`, `isize` and `usize`,
+    /// use [`mean_by`](GenericArray2d::mean_by) for those.
+    pub fn mean(&self) -> Option<f64>
+    where
+        T::Item: Clone + Into<f64>,
+    {
+        self.mean_by(|x| x.clone().into())
+    }
+
+    /// Returns the mean of all values converted by a function, returns `None` if empty.
+    pub fn mean_by(&self, f: impl FnMut(&T::Item) -> f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.values().map(f).sum();
+        Some(sum / self.len() as f64)
+    }
+
+    /// Returns the number of values that satisfy a predicate.
+    pub fn count(&self, mut pred: impl FnMut(&T::Item) -> bool) -> usize {
+        self.values().filter(|x| pred(x)).count()
+    }
+
+    /// Count values into `bins` buckets, values mapped to a bucket outside of `0..bins` are ignored.
+    pub fn histogram(&self, bins: usize, mut bin_fn: impl FnMut(&T::Item) -> usize) -> Vec<usize> {
+        let mut result = vec![0; bins];
+        for value in self.values() {
+            if let Some(count) = result.get_mut(bin_fn(value)) {
+                *count += 1;
+            }
+        }
+        result
+    }
+
+    /// Count occurrences of each distinct value.
+    pub fn counts(&self) -> BTreeMap<&T::Item, usize>
+    where
+        T::Item: Ord,
+    {
+        let mut result = BTreeMap::new();
+        for value in self.values() {
+            *result.entry(value).or_insert(0) += 1;
+        }
+        result
+    }
+
+    /// Fold each row into a value, from top to bottom.
+    pub fn fold_rows<U: Clone>(&self, init: U, mut f: impl FnMut(U, &T::Item) -> U) -> Vec<U> {
+        (0..self.height())
+            .map(|y| self.row_values(y).fold(init.clone(), &mut f))
+            .collect()
+    }
+
+    /// Fold each column into a value, from left to right.
+    pub fn fold_columns<U: Clone>(&self, init: U, mut f: impl FnMut(U, &T::Item) -> U) -> Vec<U> {
+        self.columns()
+            .map(|column| column.fold(init.clone(), &mut f))
+            .collect()
+    }
+
+    /// Returns the sum of each row, from top to bottom.
+    pub fn row_sums<'t, U: Sum<&'t T::Item>>(&'t self) -> Vec<U> {
+        (0..self.height())
+            .map(|y| self.row_values(y).sum())
+            .collect()
+    }
+
+    /// Returns the sum of each column, from left to right.
+    pub fn column_sums<'t, U: Sum<&'t T::Item>>(&'t self) -> Vec<U> {
+        self.columns().map(|column| column.sum()).collect()
+    }
+}
@@ -1,33 +1,113 @@
 //! Implementation for map/dictionary like methods.
 
+use alloc::vec::Vec;
 use mint::Vector2;
 
 use crate::{
-    GenericArray2d,
-    boundary::{Boundary, IntoBoundary},
-    storage::{Array2dStorage, Array2dStorageOwned},
+    Coordinate, GenericArray2d,
+    boundary::{Anchor, GenericBoundary, IntoBoundary},
+    storage::{Array2dStorageOwned, Array2dStorageRef},
     util::*,
 };
 
-impl<T: Array2dStorageOwned<Item: Default>> GenericArray2d<T> {
-    fn downsize(&mut self, boundary: Boundary) {
+impl<T: Array2dStorageOwned, C: Coordinate> GenericArray2d<T, C> {
+    /// Resize the array and initialize new points with a function.
+    ///
+    /// Unlike [`resize`](GenericArray2d::resize), does not require [`Default`].
+    pub fn resize_with<U: From<Vector2<C>>>(
+        &mut self,
+        boundary: impl IntoBoundary<C>,
+        mut fill: impl FnMut(U) -> T::Item,
+    ) {
+        let boundary = boundary.into_boundary();
+        if self.boundary == boundary {
+            return;
+        }
+        let old = self.boundary;
+        let data = core::mem::replace(&mut self.data, T::from_vec(Vec::new()));
+        let mut items = IterOwned {
+            iter: data.into_owned_iter().skip(self.offset),
+            position: Vector2 { x: 0, y: 0 },
+            dimension: Vector2 {
+                x: old.pitch(),
+                y: old.height(),
+            },
+            pitch: self.pitch,
+        };
+        let mut vec = Vec::with_capacity(boundary.len());
+        // Points in the intersection are ordered the same way in both arrays,
+        // so we can move them with a single pass.
+        for position in boundary.iter::<Vector2<C>>() {
+            if old.contains(position) {
+                let local = to_local(position, old.min);
+                for (p, item) in items.by_ref() {
+                    if p == local {
+                        vec.push(item);
+                        break;
+                    }
+                }
+            } else {
+                vec.push(fill(position.into()));
+            }
+        }
+        self.data = T::from_vec(vec);
+        self.boundary = boundary;
+        self.pitch = boundary.pitch();
+        self.offset = 0;
+    }
+
+    /// Resize the array and initialize new points with a value.
+    ///
+    /// Unlike [`resize`](GenericArray2d::resize), does not require [`Default`].
+    pub fn resize_filled(&mut self, boundary: impl IntoBoundary<C>, value: T::Item)
+    where
+        T::Item: Clone,
+    {
+        self.resize_with(boundary, |_: Vector2<C>| value.clone());
+    }
+
+    /// Resize the array to a new dimension, keeping the anchor point fixed,
+    /// initialize new points with a function.
+    ///
+    /// See [`GenericBoundary::anchored`].
+    pub fn resize_anchored_with<U: From<Vector2<C>>>(
+        &mut self,
+        dimension: impl Into<Vector2<C>>,
+        anchor: Anchor,
+        fill: impl FnMut(U) -> T::Item,
+    ) {
+        let boundary = self.boundary.anchored(dimension, anchor);
+        self.resize_with(boundary, fill);
+    }
+}
+
+impl<T: Array2dStorageOwned<Item: Default>, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns the backing [`Vec`], only called if the storage has one.
+    fn vec_mut(&mut self) -> &mut Vec<T::Item> {
+        self.data
+            .vec_mut()
+            .expect("Storage must be backed by a Vec.")
+    }
+
+    fn downsize(&mut self, boundary: GenericBoundary<C>) {
         let mut base1 = 0;
-        let mut base2 = offset_of(boundary.min, self.boundary.min, self.pitch);
-        let slice = self.data.slice_mut();
-        for _ in 0..boundary.dimension.y {
+        let mut base2 = self.offset + offset_of(boundary.min, self.boundary.min, self.pitch);
+        let pitch = self.pitch;
+        let slice = self.vec_mut();
+        for _ in 0..boundary.height() {
             move_within(slice, base2, base1, boundary.pitch());
             base1 += boundary.pitch();
-            base2 += self.pitch;
+            base2 += pitch;
         }
         let len = boundary.len();
         slice[len..].fill_with(Default::default)
     }
 
-    fn upsize(&mut self, from: Boundary, to: Boundary) {
-        let h = from.dimension.y as usize;
+    fn upsize(&mut self, from: GenericBoundary<C>, to: GenericBoundary<C>) {
+        let h = from.height();
         let mut base1 = from.pitch() * h;
         let mut base2 = offset_of(from.min, to.min, to.pitch()) + to.pitch() * h;
-        let slice = self.data.slice_mut();
+        let slice = self.vec_mut();
         for _ in 0..h {
             base1 -= from.pitch();
             base2 -= to.pitch();
@@ -36,36 +116,43 @@ impl<T: Array2dStorageOwned<Item: Default>> GenericArray2d<T> {
     }
 
     /// Resize the array and initialize with [`Default::default`].
-    pub fn resize(&mut self, boundary: impl IntoBoundary) {
+    ///
+    /// Resizes in place if the storage is backed by a [`Vec`].
+    pub fn resize(&mut self, boundary: impl IntoBoundary<C>) {
         let boundary = boundary.into_boundary();
 
+        if self.data.vec_mut().is_none() {
+            self.resize_with(boundary, |_: Vector2<C>| Default::default());
+            return;
+        }
         if self.is_empty() {
             self.boundary = boundary;
             self.pitch = boundary.pitch();
-            self.data.vec_mut().clear();
-            self.data
-                .vec_mut()
-                .extend((0..boundary.len()).map(|_| Default::default()));
+            self.offset = 0;
+            let vec = self.vec_mut();
+            vec.clear();
+            vec.extend((0..boundary.len()).map(|_| Default::default()));
             return;
         }
         if self.boundary == boundary && self.pitch == boundary.pitch() {
             return;
         }
         let size = boundary.len();
-        if size > self.data.slice().len() {
-            self.data.vec_mut().resize_with(size, Default::default);
+        if size > self.data.len() {
+            self.vec_mut().resize_with(size, Default::default);
         }
 
         let Some(intersection) = self.boundary.intersection(boundary) else {
-            self.data.vec_mut().fill_with(Default::default);
+            self.vec_mut().fill_with(Default::default);
             self.boundary = boundary;
             self.pitch = boundary.pitch();
+            self.offset = 0;
             return;
         };
 
         // Downsizing is ordered to avoid use after move,
         // then we can clear unused items.
-        if intersection != self.boundary || intersection.pitch() != self.pitch {
+        if intersection != self.boundary || intersection.pitch() != self.pitch || self.offset != 0 {
             self.downsize(intersection);
         }
 
@@ -76,41 +163,165 @@ impl<T: Array2dStorageOwned<Item: Default>> GenericArray2d<T> {
 
         self.boundary = boundary;
         self.pitch = boundary.pitch();
+        self.offset = 0;
+    }
+
+    /// Resize the array to a new dimension, keeping the anchor point fixed,
+    /// initialize with [`Default::default`].
+    ///
+    /// See [`GenericBoundary::anchored`].
+    pub fn resize_anchored(&mut self, dimension: impl Into<Vector2<C>>, anchor: Anchor) {
+        let boundary = self.boundary.anchored(dimension, anchor);
+        self.resize(boundary);
+    }
+
+    /// Move items into a new buffer of the given capacity,
+    /// must contain the boundary, or `min` of an empty boundary.
+    fn relocate(&mut self, capacity: GenericBoundary<C>) {
+        let mut vec = Vec::new();
+        vec.resize_with(capacity.len(), Default::default);
+        let pitch = capacity.pitch();
+        let offset = offset_of(self.boundary.min, capacity.min, pitch);
+        let width = self.width();
+        for (i, item) in self.values_mut().enumerate() {
+            vec[offset + (i / width) * pitch + i % width] = core::mem::take(item);
+        }
+        self.data = T::from_vec(vec);
+        self.pitch = pitch;
+        self.offset = offset;
+    }
+
+    /// Grow the capacity geometrically to contain the boundary.
+    fn grow_capacity(&mut self, boundary: GenericBoundary<C>) {
+        let capacity = self.capacity();
+        if capacity.union(boundary) == capacity {
+            return;
+        }
+        if capacity.is_empty() {
+            // The array is empty, `grow_within_capacity` replaces the boundary afterwards.
+            self.boundary.min = boundary.min;
+            self.relocate(boundary);
+            return;
+        }
+        let target = capacity.union(boundary);
+        let (mut min, mut max) = target.wide();
+        let (old_min, old_max) = capacity.wide();
+        // Only add slack to the directions we are growing in.
+        let slack = Vector2 {
+            x: max.x - min.x,
+            y: max.y - min.y,
+        };
+        if min.x < old_min.x {
+            min.x -= slack.x;
+        }
+        if min.y < old_min.y {
+            min.y -= slack.y;
+        }
+        if max.x > old_max.x {
+            max.x += slack.x;
+        }
+        if max.y > old_max.y {
+            max.y += slack.y;
+        }
+        self.relocate(GenericBoundary::saturating_from_wide(min, max));
+    }
+
+    /// Change the boundary to a larger boundary within the capacity,
+    /// new items are initialized with [`Default::default`].
+    fn grow_within_capacity(&mut self, boundary: GenericBoundary<C>) {
+        let old = self.boundary;
+        let capacity = self.capacity();
+        self.offset = offset_of(boundary.min, capacity.min, self.pitch);
+        self.boundary = boundary;
+        let pitch = self.pitch;
+        let width = boundary.pitch();
+        let left = if old.is_empty() {
+            width
+        } else {
+            to_local(old.min, boundary.min).x
+        };
+        let right = if old.is_empty() {
+            width
+        } else {
+            left + old.pitch()
+        };
+        for y in 0..boundary.height() {
+            let start = self.offset + y * pitch;
+            let y = boundary.min.y.to_i128() + y as i128;
+            let (old_min, old_max) = old.wide();
+            let ranges = if old.is_empty() || y < old_min.y || y >= old_max.y {
+                [(start, width), (start, 0)]
+            } else {
+                [(start, left), (start + right, width - right)]
+            };
+            for (start, len) in ranges {
+                for item in self.data.iter_strided_mut(start, len, pitch, 1) {
+                    *item = Default::default();
+                }
+            }
+        }
+    }
+
+    /// Reserve capacity so that growing the array within the boundary
+    /// via [`insert`](GenericArray2d::insert) or [`resize_containing`](GenericArray2d::resize_containing)
+    /// does not reallocate.
+    pub fn reserve(&mut self, boundary: impl IntoBoundary<C>) {
+        let capacity = self.capacity();
+        let mut target = capacity
+            .union(self.boundary)
+            .union(boundary.into_boundary());
+        if self.is_empty() {
+            // `capacity()` is relative to `min`, which must stay in place.
+            target = target.union(GenericBoundary::from_point(self.boundary.min));
+        }
+        if target != capacity {
+            self.relocate(target);
+        }
+    }
+
+    /// Shrink the capacity to the boundary of the array.
+    pub fn shrink_to_fit(&mut self) {
+        if self.data.vec_mut().is_none() {
+            self.relocate(self.boundary);
+            return;
+        }
+        if self.offset != 0 || self.pitch != self.boundary.pitch() {
+            self.downsize(self.boundary);
+            self.pitch = self.boundary.pitch();
+            self.offset = 0;
+        }
+        let len = self.boundary.len();
+        let vec = self.vec_mut();
+        vec.truncate(len);
+        vec.shrink_to_fit();
     }
 
     /// Insert a point into an array and potentially expanding the size with [`Default`] values.
-    pub fn insert(&mut self, position: impl Into<Vector2<i32>>, value: T::Item) {
+    ///
+    /// Growth is amortized, see [`resize_containing`](GenericArray2d::resize_containing).
+    pub fn insert(&mut self, position: impl Into<Vector2<C>>, value: T::Item) {
         let position = position.into();
-        if self.is_empty() {
-            self.boundary = Boundary::from_point(position);
-            self.pitch = 1;
-            self.data = T::from_vec(vec![value]);
-        } else if let Some(v) = self.get_mut(position) {
+        if let Some(v) = self.get_mut(position) {
             *v = value;
         } else {
-            let min = vec_min(self.boundary.min, position);
-            let max = vec_max(self.max_point(), position);
-            self.resize(Boundary::min_max(min, max));
+            self.resize_containing(GenericBoundary::from_point(position));
             if let Some(v) = self.get_mut(position) {
                 *v = value;
             }
         }
     }
 
-    /// Expand the array to include a boundary.
-    pub fn resize_containing(&mut self, boundary: Boundary) {
-        if self.is_empty() {
-            self.resize(boundary);
-        } else {
-            let boundary = boundary.into_boundary();
-            let min = vec_min(self.boundary.min, boundary.min);
-            let max = vec_max(
-                self.boundary.max_non_inclusive(),
-                boundary.max_non_inclusive(),
-            );
-            let dimension = i2u(sub(max, min));
-            self.resize(Boundary { min, dimension });
+    /// Expand the array to include a boundary, empty boundaries are ignored.
+    ///
+    /// Like `Vec::push`, this reserves extra capacity in the direction of growth,
+    /// so repeatedly growing the array is amortized `O(1)` per new item.
+    pub fn resize_containing(&mut self, boundary: GenericBoundary<C>) {
+        let target = self.boundary.union(boundary);
+        if target == self.boundary {
+            return;
         }
+        self.grow_capacity(target);
+        self.grow_within_capacity(target);
     }
 
     /// Insert points into the array,
@@ -119,7 +330,7 @@ impl<T: Array2dStorageOwned<Item: Default>> GenericArray2d<T> {
     /// # Returns
     ///
     /// Number of points discarded.
-    pub fn try_extend<U: Into<Vector2<i32>>>(
+    pub fn try_extend<U: Into<Vector2<C>>>(
         &mut self,
         positions: impl IntoIterator<Item = (U, T::Item)>,
     ) -> usize {
@@ -138,40 +349,42 @@ impl<T: Array2dStorageOwned<Item: Default>> GenericArray2d<T> {
     /// requires a [`Clone`] iterator to calculate the boundary on the first pass.
     ///
     /// For standard rust types like `&[T]` or `Vec<T>`, use `slice.iter().copied()`.
-    pub fn extend<U: Into<Vector2<i32>>>(
+    ///
+    /// Since the final boundary is known up front, unlike [`resize_containing`](GenericArray2d::resize_containing)
+    /// this does not reserve extra capacity.
+    pub fn extend<U: Into<Vector2<C>>>(
         &mut self,
         positions: impl IntoIterator<Item = (U, T::Item)> + Clone,
     ) {
-        let boundary = Boundary::from_iter(positions.clone().into_iter().map(|(x, _)| x));
-        self.resize_containing(boundary);
+        let boundary = GenericBoundary::from_iter(positions.clone().into_iter().map(|(x, _)| x));
+        self.resize(self.boundary.union(boundary));
         self.try_extend(positions);
     }
 
     /// Extend the array to cover both array's boundaries and copy the other array into this array.
-    pub fn merge<U: Array2dStorage<Item = T::Item>>(&mut self, array: &GenericArray2d<U>)
+    ///
+    /// Merging an empty array has no effect.
+    pub fn merge<U: Array2dStorageRef<Item = T::Item>>(&mut self, array: &GenericArray2d<U, C>)
     where
         T::Item: Clone,
     {
-        if self.is_empty() {
-            self.resize(array.boundary);
-        } else {
-            let min = vec_min(self.boundary.min, array.boundary.min);
-            let max = vec_max(
-                self.boundary.max_non_inclusive(),
-                array.boundary.max_non_inclusive(),
-            );
-            let dimension = i2u(sub(max, min));
-            self.resize(Boundary { min, dimension });
-        }
+        self.resize(self.boundary.union(array.boundary));
 
-        self.paint(array, [0, 0], |source, incoming| *source = incoming.clone());
+        self.paint(
+            array,
+            Vector2 {
+                x: C::ZERO,
+                y: C::ZERO,
+            },
+            |source, incoming| *source = incoming.clone(),
+        );
     }
 
     /// Increase dimension both horizontally and vertically.
     ///
     /// For example expanding `[0, 0]..=[0, 0]` by `[2, 1]`
-    /// results in `[-2, -1]..=[2, 1]`.
-    pub fn expand(&mut self, by: impl Into<Vector2<i32>>) {
+    /// results in `[-2, -1]..=[0, 0]`.
+    pub fn expand(&mut self, by: impl Into<Vector2<C>>) {
         let target = self.boundary.expand_by(by);
         self.resize(target);
     }
@@ -0,0 +1,390 @@
+//! Run-length encoded storage for mostly uniform maps.
+
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+use mint::Vector2;
+
+use crate::{
+    Array2d, Boundary, GenericArray2d,
+    boundary::IntoBoundary,
+    storage::{Array2dStorage, Array2dStorageRef, Array2dStorageSet},
+};
+
+/// A run of equal items, ending before local `x` position `end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Run<T> {
+    end: u32,
+    value: T,
+}
+
+/// A 2d array where each row is stored as runs of equal items.
+///
+/// Adjacent runs always have different values, so memory usage scales with
+/// the number of value changes instead of the number of points.
+/// [`as_array`](RleArray2d::as_array) exposes the array to generic array methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RleArray2d<T> {
+    rows: Vec<Vec<Run<T>>>,
+    boundary: Boundary,
+}
+
+impl<T> Default for RleArray2d<T> {
+    fn default() -> Self {
+        RleArray2d {
+            rows: Vec::new(),
+            boundary: Boundary::EMPTY,
+        }
+    }
+}
+
+impl<T> RleArray2d<T> {
+    /// Initialize with a single value.
+    pub fn new_filled(boundary: impl IntoBoundary, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let boundary = boundary.into_boundary();
+        let row = if boundary.dimension.x == 0 {
+            Vec::new()
+        } else {
+            vec![Run {
+                end: boundary.dimension.x,
+                value,
+            }]
+        };
+        RleArray2d {
+            rows: vec![row; boundary.dimension.y as usize],
+            boundary,
+        }
+    }
+
+    /// Initialize with [`Default::default`].
+    pub fn new(boundary: impl IntoBoundary) -> Self
+    where
+        T: Clone + Default,
+    {
+        Self::new_filled(boundary, T::default())
+    }
+
+    /// Initialize with a function, adjacent equal values are merged into runs.
+    pub fn init<U: From<Vector2<i32>>>(
+        boundary: impl IntoBoundary,
+        mut init: impl FnMut(U) -> T,
+    ) -> Self
+    where
+        T: PartialEq,
+    {
+        let boundary = boundary.into_boundary();
+        let rows = (0..boundary.dimension.y as i32)
+            .map(|y| {
+                let y = boundary.min.y.wrapping_add(y);
+                encode((0..boundary.dimension.x as i32).map(|x| {
+                    init(
+                        Vector2 {
+                            x: boundary.min.x.wrapping_add(x),
+                            y,
+                        }
+                        .into(),
+                    )
+                }))
+            })
+            .collect();
+        RleArray2d { rows, boundary }
+    }
+
+    /// Returns true if contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.boundary.is_empty()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    /// Returns the boundary of the array.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.boundary.contains(position)
+    }
+
+    /// Returns the total number of runs in the array.
+    pub fn run_count(&self) -> usize {
+        self.rows.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the row and the local `x` position of a point if in boundary.
+    fn locate(&self, position: Vector2<i32>) -> Option<(usize, u32)> {
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        let x = position.x.abs_diff(self.boundary.min.x);
+        let y = position.y.abs_diff(self.boundary.min.y);
+        Some((y as usize, x))
+    }
+
+    /// Returns the row and the local `x` position of an offset in row major order.
+    fn locate_index(&self, index: usize) -> Option<(usize, u32)> {
+        let width = self.boundary.dimension.x as usize;
+        if index >= self.len() {
+            return None;
+        }
+        Some((index / width, (index % width) as u32))
+    }
+
+    fn get_local(&self, y: usize, x: u32) -> Option<&T> {
+        let row = &self.rows[y];
+        row.get(row.partition_point(|run| run.end <= x))
+            .map(|run| &run.value)
+    }
+
+    /// Returns a point.
+    pub fn get(&self, position: impl Into<Vector2<i32>>) -> Option<&T> {
+        let (y, x) = self.locate(position.into())?;
+        self.get_local(y, x)
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    ///
+    /// Splits the run containing the point and merges it with neighbours of the same value.
+    pub fn set(&mut self, position: impl Into<Vector2<i32>>, value: T) -> bool
+    where
+        T: Clone + PartialEq,
+    {
+        let Some((y, x)) = self.locate(position.into()) else {
+            return false;
+        };
+        self.set_local(y, x, value);
+        true
+    }
+
+    fn set_local(&mut self, y: usize, x: u32, value: T)
+    where
+        T: Clone + PartialEq,
+    {
+        let row = &mut self.rows[y];
+        let i = row.partition_point(|run| run.end <= x);
+        if row[i].value == value {
+            return;
+        }
+        let start = if i == 0 { 0 } else { row[i - 1].end };
+        let end = row[i].end;
+        let old = row[i].value.clone();
+        let mut pieces = Vec::with_capacity(3);
+        if start < x {
+            pieces.push(Run {
+                end: x,
+                value: old.clone(),
+            });
+        }
+        pieces.push(Run { end: x + 1, value });
+        if x + 1 < end {
+            pieces.push(Run { end, value: old });
+        }
+        let j = i + usize::from(start < x);
+        row.splice(i..=i, pieces);
+        if j + 1 < row.len() && row[j + 1].value == row[j].value {
+            row[j].end = row[j + 1].end;
+            row.remove(j + 1);
+        }
+        if j > 0 && row[j - 1].value == row[j].value {
+            row[j - 1].end = row[j].end;
+            row.remove(j);
+        }
+    }
+
+    /// Set all points to a value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        *self = Self::new_filled(self.boundary, value);
+    }
+
+    /// Iterate through runs of a row as world `x` ranges and values,
+    /// returns an empty iterator if `y` is out of bounds.
+    pub fn row_runs(&self, y: i32) -> impl Iterator<Item = (Range<i32>, &T)> {
+        let min = self.boundary.min.x;
+        let row = y
+            .checked_sub(self.boundary.min.y)
+            .and_then(|y| usize::try_from(y).ok())
+            .and_then(|y| self.rows.get(y))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        row.iter().scan(0, move |start, run| {
+            let range = min.wrapping_add_unsigned(*start)..min.wrapping_add_unsigned(run.end);
+            *start = run.end;
+            Some((range, &run.value))
+        })
+    }
+
+    /// Iterate through rows of the array, each row is reconstructed from its runs.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.rows.iter().map(|row| {
+            row.iter()
+                .scan(0, |start, run| {
+                    let len = run.end - *start;
+                    *start = run.end;
+                    Some(core::iter::repeat_n(&run.value, len as usize))
+                })
+                .flatten()
+        })
+    }
+
+    /// Returns all values in row major order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.rows().flatten()
+    }
+
+    /// Iterate through pairs of points and values in row major order.
+    pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, &T)> {
+        self.boundary
+            .iter::<Vector2<i32>>()
+            .zip(self.values())
+            .map(|(p, v)| (p.into(), v))
+    }
+
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+        self.boundary.displace(by);
+    }
+
+    /// View the array as a row major [`GenericArray2d`],
+    /// which enables generic methods like [`zip`](GenericArray2d::zip) or [`sum`](GenericArray2d::sum).
+    pub fn as_array(&self) -> GenericArray2d<&RleArray2d<T>> {
+        GenericArray2d {
+            data: self,
+            boundary: self.boundary,
+            pitch: self.boundary.pitch(),
+            offset: 0,
+        }
+    }
+
+    /// View the array as a mutable row major [`GenericArray2d`].
+    pub fn as_array_mut(&mut self) -> GenericArray2d<&mut RleArray2d<T>> {
+        GenericArray2d {
+            boundary: self.boundary,
+            pitch: self.boundary.pitch(),
+            offset: 0,
+            data: self,
+        }
+    }
+
+    /// Clone into a dense [`Array2d`].
+    pub fn cloned(&self) -> Array2d<T>
+    where
+        T: Clone,
+    {
+        let mut values = self.values();
+        Array2d::init(self.boundary, |_: Vector2<i32>| {
+            values.next().expect("Must be in boundary.").clone()
+        })
+    }
+}
+
+impl<T> Array2dStorage for &RleArray2d<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        Array2dStorageRef::get(self, index).cloned()
+    }
+}
+
+impl<T> Array2dStorageRef for &RleArray2d<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        let (y, x) = self.locate_index(index)?;
+        self.get_local(y, x)
+    }
+}
+
+impl<T> Array2dStorage for &mut RleArray2d<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        Array2dStorageRef::get(self, index).cloned()
+    }
+}
+
+impl<T> Array2dStorageRef for &mut RleArray2d<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        let (y, x) = self.locate_index(index)?;
+        self.get_local(y, x)
+    }
+}
+
+/// Setting a point splits and merges runs like [`RleArray2d::set`].
+impl<T: Clone + PartialEq> Array2dStorageSet for &mut RleArray2d<T> {
+    fn set(&mut self, index: usize, value: T) -> bool {
+        let Some((y, x)) = self.locate_index(index) else {
+            return false;
+        };
+        self.set_local(y, x, value);
+        true
+    }
+}
+
+/// Merge adjacent equal items into runs.
+fn encode<T: PartialEq>(items: impl IntoIterator<Item = T>) -> Vec<Run<T>> {
+    let mut runs: Vec<Run<T>> = Vec::new();
+    for (x, item) in items.into_iter().enumerate() {
+        let end = x as u32 + 1;
+        match runs.last_mut() {
+            Some(run) if run.value == item => run.end = end,
+            _ => runs.push(Run { end, value: item }),
+        }
+    }
+    runs
+}
+
+impl<S: Array2dStorageRef<Item: Clone + PartialEq>> From<&GenericArray2d<S>>
+    for RleArray2d<S::Item>
+{
+    fn from(value: &GenericArray2d<S>) -> Self {
+        let width = value.width();
+        let mut values = value.values();
+        let rows = (0..value.height())
+            .map(|_| encode(values.by_ref().take(width).cloned()))
+            .collect();
+        RleArray2d {
+            rows,
+            boundary: value.boundary(),
+        }
+    }
+}
+
+impl<T: PartialEq> From<Array2d<T>> for RleArray2d<T> {
+    fn from(value: Array2d<T>) -> Self {
+        let boundary = value.boundary();
+        let (width, height) = (value.width(), value.height());
+        let mut values = value.iter_owned::<Vector2<i32>>().map(|(_, v)| v);
+        let rows = (0..height)
+            .map(|_| encode(values.by_ref().take(width)))
+            .collect();
+        RleArray2d { rows, boundary }
+    }
+}
+
+impl<T: Clone> From<&RleArray2d<T>> for Array2d<T> {
+    fn from(value: &RleArray2d<T>) -> Self {
+        value.cloned()
+    }
+}
@@ -0,0 +1,291 @@
+//! A fixed size window with toroidal addressing.
+
+use alloc::vec::Vec;
+use mint::Vector2;
+
+use crate::{Array2d, Boundary, boundary::IntoBoundary, util::*};
+
+/// A fixed size 2d array that can be moved cheaply.
+///
+/// Points are stored at `position mod dimension`,
+/// so moving the boundary via [`recenter`](ScrollingArray2d::recenter)
+/// only reinitializes newly exposed points instead of moving the entire buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrollingArray2d<T> {
+    data: Vec<T>,
+    boundary: Boundary,
+}
+
+impl<T> Default for ScrollingArray2d<T> {
+    fn default() -> Self {
+        ScrollingArray2d {
+            data: Vec::new(),
+            boundary: Boundary::EMPTY,
+        }
+    }
+}
+
+impl<T> ScrollingArray2d<T> {
+    /// Initialize with a function.
+    pub fn init<U: From<Vector2<i32>>>(
+        boundary: impl IntoBoundary,
+        mut init: impl FnMut(U) -> T,
+    ) -> Self {
+        let boundary = boundary.into_boundary();
+        let mut data = Vec::with_capacity(boundary.len());
+        data.extend((0..boundary.len()).map(|i| init(position_of(boundary, i).into())));
+        ScrollingArray2d { data, boundary }
+    }
+
+    /// Initialize with [`Default::default`].
+    pub fn new(boundary: impl IntoBoundary) -> Self
+    where
+        T: Default,
+    {
+        let boundary = boundary.into_boundary();
+        let mut data = Vec::new();
+        data.resize_with(boundary.len(), Default::default);
+        ScrollingArray2d { data, boundary }
+    }
+
+    /// Returns true if contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.boundary.is_empty()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    /// Returns the boundary of the array.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.boundary.contains(position)
+    }
+
+    fn index_of(&self, position: Vector2<i32>) -> usize {
+        // Dimensions may exceed `i32::MAX`, wrap in `i64` instead.
+        let x = (position.x as i64).rem_euclid(self.boundary.dimension.x as i64) as usize;
+        let y = (position.y as i64).rem_euclid(self.boundary.dimension.y as i64) as usize;
+        y * self.boundary.pitch() + x
+    }
+
+    /// Returns a point in world coordinates.
+    pub fn get(&self, position: impl Into<Vector2<i32>>) -> Option<&T> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        self.data.get(self.index_of(position))
+    }
+
+    /// Returns a point in world coordinates.
+    pub fn get_mut(&mut self, position: impl Into<Vector2<i32>>) -> Option<&mut T> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        let index = self.index_of(position);
+        self.data.get_mut(index)
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, position: impl Into<Vector2<i32>>, value: T) -> bool {
+        if let Some(v) = self.get_mut(position) {
+            *v = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns a row in world coordinates as 2 continuous slices, from left to right.
+    pub fn row(&self, y: i32) -> Option<(&[T], &[T])> {
+        if self.is_empty() || y < self.boundary.min.y || y > self.boundary.max().y {
+            return None;
+        }
+        let pitch = self.boundary.pitch();
+        let start = self.index_of(Vector2 {
+            x: self.boundary.min.x,
+            y,
+        });
+        let row_start = start - start % pitch;
+        let row = &self.data[row_start..row_start + pitch];
+        let (left, right) = row.split_at(start - row_start);
+        Some((right, left))
+    }
+
+    /// Returns rows in world coordinates as pairs of continuous slices, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = (&[T], &[T])> {
+        let min = self.boundary.min.y;
+        (0..self.boundary.dimension.y).filter_map(move |y| self.row(min.wrapping_add(y as i32)))
+    }
+
+    /// Returns rows in world coordinates as pairs of continuous slices, from top to bottom.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = (&mut [T], &mut [T])> {
+        let pitch = self.boundary.pitch().max(1);
+        let (sx, sy) = if self.is_empty() {
+            (0, 0)
+        } else {
+            let start = self.index_of(self.boundary.min);
+            (start % pitch, start / pitch)
+        };
+        let (top, bottom) = self.data.split_at_mut(sy * pitch);
+        bottom
+            .chunks_mut(pitch)
+            .chain(top.chunks_mut(pitch))
+            .map(move |row| {
+                let (left, right) = row.split_at_mut(sx);
+                (right, left)
+            })
+    }
+
+    /// Returns all values in world coordinates in row major order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.rows().flat_map(|(a, b)| a.iter().chain(b))
+    }
+
+    /// Returns all values in world coordinates in row major order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.rows_mut().flat_map(|(a, b)| a.iter_mut().chain(b))
+    }
+
+    /// Iterate through pairs of points and values in the array.
+    pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, &T)> {
+        self.boundary.iter::<U>().zip(self.values())
+    }
+
+    /// Iterate through pairs of points and values in the array.
+    pub fn iter_mut<U: From<Vector2<i32>>>(&mut self) -> impl Iterator<Item = (U, &mut T)> {
+        self.boundary.iter::<U>().zip(self.values_mut())
+    }
+
+    /// Move the window to a new boundary, newly exposed points are initialized with a function.
+    ///
+    /// If the dimension changes, all points are reinitialized.
+    pub fn recenter_with<U: From<Vector2<i32>>>(
+        &mut self,
+        boundary: impl IntoBoundary,
+        mut init: impl FnMut(U) -> T,
+    ) {
+        let boundary = boundary.into_boundary();
+        let old = self.boundary;
+        if old.dimension != boundary.dimension {
+            *self = Self::init(boundary, init);
+            return;
+        }
+        if old == boundary {
+            return;
+        }
+        self.boundary = boundary;
+        // Iterate in `i128` so boundaries ending at `i32::MAX` do not wrap.
+        let (old_min, old_max) = old.wide();
+        let (min, max) = boundary.wide();
+        let mut reinit = |this: &mut Self, x: i128, y: i128| {
+            let position = Vector2 {
+                x: x as i32,
+                y: y as i32,
+            };
+            let index = this.index_of(position);
+            this.data[index] = init(position.into());
+        };
+        for y in min.y..max.y {
+            if y < old_min.y || y >= old_max.y {
+                for x in min.x..max.x {
+                    reinit(self, x, y);
+                }
+            } else {
+                for x in min.x..old_min.x.min(max.x) {
+                    reinit(self, x, y);
+                }
+                for x in old_max.x.max(min.x)..max.x {
+                    reinit(self, x, y);
+                }
+            }
+        }
+    }
+
+    /// Move the window to a new boundary, newly exposed points are initialized with [`Default::default`].
+    ///
+    /// If the dimension changes, all points are reinitialized.
+    pub fn recenter(&mut self, boundary: impl IntoBoundary)
+    where
+        T: Default,
+    {
+        self.recenter_with(boundary, |_: Vector2<i32>| Default::default());
+    }
+
+    /// Move the window by an offset, newly exposed points are initialized with [`Default::default`].
+    pub fn scroll(&mut self, by: impl Into<Vector2<i32>>)
+    where
+        T: Default,
+    {
+        self.recenter(self.boundary.displace_by(by));
+    }
+
+    /// Clone into a row major [`Array2d`].
+    pub fn cloned(&self) -> Array2d<T>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.len());
+        data.extend(self.values().cloned());
+        Array2d::from_vec(data, self.boundary)
+    }
+}
+
+/// Returns the position of an index in the underlying buffer relative to `boundary.min`.
+fn local_of(boundary: Boundary, index: usize) -> Vector2<usize> {
+    let w = boundary.dimension.x as i64;
+    let h = boundary.dimension.y as i64;
+    let x = (index % boundary.pitch()) as i64;
+    let y = (index / boundary.pitch()) as i64;
+    Vector2 {
+        x: (x - (boundary.min.x as i64).rem_euclid(w)).rem_euclid(w) as usize,
+        y: (y - (boundary.min.y as i64).rem_euclid(h)).rem_euclid(h) as usize,
+    }
+}
+
+/// Returns the position of an index in the underlying buffer.
+fn position_of(boundary: Boundary, index: usize) -> Vector2<i32> {
+    let local = local_of(boundary, index);
+    from_local(boundary.min, local.x, local.y)
+}
+
+impl<T> From<Array2d<T>> for ScrollingArray2d<T> {
+    fn from(value: Array2d<T>) -> Self {
+        let boundary = value.boundary();
+        let mut items: Vec<_> = value
+            .iter_owned::<Vector2<i32>>()
+            .map(|(_, v)| Some(v))
+            .collect();
+        let data = (0..boundary.len())
+            .map(|i| {
+                let local = local_of(boundary, i);
+                items[local.y * boundary.pitch() + local.x]
+                    .take()
+                    .expect("Each item is moved once.")
+            })
+            .collect();
+        ScrollingArray2d { data, boundary }
+    }
+}
+
+impl<T> From<ScrollingArray2d<T>> for Array2d<T> {
+    fn from(value: ScrollingArray2d<T>) -> Self {
+        let boundary = value.boundary;
+        let index_of = |position| value.index_of(position);
+        let indices: Vec<_> = boundary.iter::<Vector2<i32>>().map(index_of).collect();
+        let mut items: Vec<_> = value.data.into_iter().map(Some).collect();
+        let data = indices
+            .into_iter()
+            .map(|i| items[i].take().expect("Each item is moved once."))
+            .collect();
+        Array2d::from_vec(data, boundary)
+    }
+}
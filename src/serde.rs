@@ -1,12 +1,14 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    Boundary, GenericArray2d,
+    Coordinate, GenericArray2d, GenericBoundary,
     serde::ser::SerializeRows,
-    traits::{Array2dStorage, Array2dStorageOwned},
+    traits::{Array2dStorageOwned, Array2dStorageRef},
 };
 
-impl<T: Array2dStorage<Item: Serialize>> Serialize for GenericArray2d<T> {
+impl<T: Array2dStorageRef<Item: Serialize>, C: Coordinate<Unsigned: Serialize> + Serialize>
+    Serialize for GenericArray2d<T, C>
+{
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         ser::Array2d {
             min: self.boundary.min.into(),
@@ -17,23 +19,19 @@ impl<T: Array2dStorage<Item: Serialize>> Serialize for GenericArray2d<T> {
     }
 }
 
-impl<'de, T: Array2dStorageOwned<Item: Deserialize<'de>>> Deserialize<'de> for GenericArray2d<T> {
+impl<
+    'de,
+    T: Array2dStorageOwned<Item: Deserialize<'de>>,
+    C: Coordinate<Unsigned: Deserialize<'de>> + Deserialize<'de>,
+> Deserialize<'de> for GenericArray2d<T, C>
+{
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let result = <de::Array2d<T::Item>>::deserialize(deserializer)?;
-        let boundary = Boundary {
+        let result = <de::Array2d<C, C::Unsigned, T::Item>>::deserialize(deserializer)?;
+        let boundary = GenericBoundary {
             min: result.min.into(),
             dimension: result.dimension.into(),
         };
-        if result.data.len() < boundary.len() {
-            return Err(serde::de::Error::custom(
-                "Array2d does not contain enough items.",
-            ));
-        }
-        Ok(GenericArray2d {
-            data: T::from_vec(result.data),
-            boundary,
-            pitch: boundary.pitch(),
-        })
+        GenericArray2d::try_from_vec(result.data, boundary).map_err(serde::de::Error::custom)
     }
 }
 
@@ -53,15 +51,15 @@ mod ser {
     }
 
     #[derive(Serialize)]
-    #[serde(bound(serialize = "SerializeRows<T>: Serialize"))]
-    pub(crate) struct Array2d<T> {
-        pub min: [i32; 2],
-        pub dimension: [u32; 2],
+    #[serde(bound(serialize = "C: Serialize, U: Serialize, SerializeRows<T>: Serialize"))]
+    pub(crate) struct Array2d<C, U, T> {
+        pub min: [C; 2],
+        pub dimension: [U; 2],
         pub data: SerializeRows<T>,
     }
 }
 
-impl Serialize for Boundary {
+impl<C: Coordinate<Unsigned: Serialize> + Serialize> Serialize for GenericBoundary<C> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         boundary::Boundary {
             min: self.min.into(),
@@ -71,10 +69,12 @@ impl Serialize for Boundary {
     }
 }
 
-impl<'de> Deserialize<'de> for Boundary {
+impl<'de, C: Coordinate<Unsigned: Deserialize<'de>> + Deserialize<'de>> Deserialize<'de>
+    for GenericBoundary<C>
+{
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let boundary = <boundary::Boundary>::deserialize(deserializer)?;
-        Ok(Boundary {
+        let boundary = <boundary::Boundary<C, C::Unsigned>>::deserialize(deserializer)?;
+        Ok(GenericBoundary {
             min: boundary.min.into(),
             dimension: boundary.dimension.into(),
         })
@@ -82,12 +82,13 @@ impl<'de> Deserialize<'de> for Boundary {
 }
 
 mod de {
+    use alloc::vec::Vec;
     use serde::Deserialize;
 
     #[derive(Deserialize)]
-    pub(crate) struct Array2d<T> {
-        pub min: [i32; 2],
-        pub dimension: [u32; 2],
+    pub(crate) struct Array2d<C, U, T> {
+        pub min: [C; 2],
+        pub dimension: [U; 2],
         pub data: Vec<T>,
     }
 }
@@ -96,8 +97,8 @@ mod boundary {
     use serde::{Deserialize, Serialize};
 
     #[derive(Serialize, Deserialize)]
-    pub(crate) struct Boundary {
-        pub min: [i32; 2],
-        pub dimension: [u32; 2],
+    pub(crate) struct Boundary<C, U> {
+        pub min: [C; 2],
+        pub dimension: [U; 2],
     }
 }
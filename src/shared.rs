@@ -0,0 +1,141 @@
+//! Copy-on-write storage shared between snapshots.
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::{
+    Array2d, GenericArray2d,
+    storage::{
+        Array2dStorage, Array2dStorageMut, Array2dStorageOwned, Array2dStorageRef,
+        Array2dStorageSlice, Array2dStorageSliceMut, strided, strided_mut,
+    },
+};
+
+/// A 2d array backed by a reference counted [`Vec`].
+///
+/// Cloning is cheap and shares the buffer, mutating methods like
+/// [`get_mut`](GenericArray2d::get_mut), [`slice_mut`](GenericArray2d::slice_mut)
+/// or [`resize`](GenericArray2d::resize) copy the buffer only if it is shared.
+pub type SharedArray2d<T> = GenericArray2d<Arc<Vec<T>>>;
+
+impl<T> Array2dStorage for Arc<Vec<T>> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().get(index).cloned()
+    }
+}
+
+impl<T> Array2dStorageRef for Arc<Vec<T>> {
+    fn get(&self, index: usize) -> Option<&Self::Item> {
+        self.as_slice().get(index)
+    }
+
+    fn iter_strided(
+        &self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &Self::Item> {
+        strided(self, start, width, pitch, height)
+    }
+}
+
+impl<T: Clone> Array2dStorageMut for Arc<Vec<T>> {
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+        Arc::make_mut(self).get_mut(index)
+    }
+
+    fn iter_strided_mut(
+        &mut self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &mut Self::Item> {
+        strided_mut(
+            Arc::make_mut(self).as_mut_slice(),
+            start,
+            width,
+            pitch,
+            height,
+        )
+    }
+}
+
+impl<T> Array2dStorageSlice for Arc<Vec<T>> {
+    fn slice(&self) -> &[Self::Item] {
+        self
+    }
+}
+
+impl<T: Clone> Array2dStorageSliceMut for Arc<Vec<T>> {
+    fn slice_mut(&mut self) -> &mut [Self::Item] {
+        Arc::make_mut(self).as_mut_slice()
+    }
+}
+
+impl<T: Clone> Array2dStorageOwned for Arc<Vec<T>> {
+    fn from_vec(vec: Vec<Self::Item>) -> Self {
+        Arc::new(vec)
+    }
+
+    fn into_owned_iter(self) -> impl Iterator<Item = Self::Item> {
+        Arc::unwrap_or_clone(self).into_iter()
+    }
+
+    fn vec_mut(&mut self) -> Option<&mut Vec<Self::Item>> {
+        Some(Arc::make_mut(self))
+    }
+}
+
+impl<T> SharedArray2d<T> {
+    /// Returns true if the buffer is shared with another array.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.data) > 1 || Arc::weak_count(&self.data) > 0
+    }
+
+    /// Returns true if both arrays share the same buffer.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+
+    /// Convert into an [`Array2d`], copies the buffer only if shared.
+    pub fn into_unshared(self) -> Array2d<T>
+    where
+        T: Clone,
+    {
+        GenericArray2d {
+            data: Arc::unwrap_or_clone(self.data),
+            boundary: self.boundary,
+            pitch: self.pitch,
+            offset: self.offset,
+        }
+    }
+}
+
+impl<T> Array2d<T> {
+    /// Convert into a [`SharedArray2d`] without copying.
+    pub fn into_shared(self) -> SharedArray2d<T> {
+        GenericArray2d {
+            data: Arc::new(self.data),
+            boundary: self.boundary,
+            pitch: self.pitch,
+            offset: self.offset,
+        }
+    }
+}
+
+impl<T> From<Array2d<T>> for SharedArray2d<T> {
+    fn from(value: Array2d<T>) -> Self {
+        value.into_shared()
+    }
+}
@@ -1,62 +1,210 @@
-/// A readable slice storage of `GenericArray2d`.
+use alloc::vec::Vec;
+/// The minimal readable storage of `GenericArray2d`, a random access sequence of items.
+///
+/// Items are read by value, so packed storages that cannot return references,
+/// like bit sets, can implement this trait.
+/// Storages that own their items should also implement [`Array2dStorageRef`].
 pub trait Array2dStorage {
     type Item;
-    fn slice(&self) -> &[Self::Item];
-}
 
-/// A mutable slice storage of `GenericArray2d`.
-pub trait Array2dStorageMut: Array2dStorage {
-    fn slice_mut(&mut self) -> &mut [Self::Item];
+    /// Returns the number of items in the storage.
+    fn len(&self) -> usize;
+
+    /// Returns true if the storage contains no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a clone of an item by its offset.
+    fn get_cloned(&self, index: usize) -> Option<Self::Item>
+    where
+        Self::Item: Clone;
 }
 
-/// An owned growable storage of `GenericArray2d`.
-pub trait Array2dStorageOwned: Array2dStorageMut {
-    fn vec_mut(&mut self) -> &mut Vec<Self::Item>;
-    fn from_vec(vec: Vec<Self::Item>) -> Self;
-    fn into_owned_iter(self) -> impl Iterator<Item = Self::Item>;
+/// The minimal writable storage of `GenericArray2d`, items are written by value.
+///
+/// Implemented on all [`Array2dStorageMut`].
+pub trait Array2dStorageSet: Array2dStorage {
+    /// Set an item by its offset, returns `false` if out of bounds.
+    fn set(&mut self, index: usize, value: Self::Item) -> bool;
 }
 
-impl<T> Array2dStorage for &[T] {
-    type Item = T;
+/// A readable random access storage that can return references to its items.
+///
+/// Required for methods that return references, like `get` and `iter`,
+/// contiguous storages should also implement [`Array2dStorageSlice`].
+pub trait Array2dStorageRef: Array2dStorage {
+    /// Returns an item by its offset.
+    fn get(&self, index: usize) -> Option<&Self::Item>;
 
-    fn slice(&self) -> &[Self::Item] {
-        self
+    /// Iterate through `height` runs of `width` items,
+    /// the first run starts at `start` and each run starts `pitch` after the previous.
+    fn iter_strided(
+        &self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &Self::Item> {
+        (0..height).flat_map(move |y| {
+            (0..width).map(move |x| {
+                self.get(start + y * pitch + x)
+                    .expect("Index out of bounds.")
+            })
+        })
     }
 }
 
-impl<T> Array2dStorage for &mut [T] {
-    type Item = T;
+/// A mutable random access storage of `GenericArray2d`.
+pub trait Array2dStorageMut: Array2dStorageRef {
+    /// Returns an item by its offset.
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Item>;
 
-    fn slice(&self) -> &[Self::Item] {
-        self
-    }
+    /// Iterate through `height` runs of `width` items,
+    /// the first run starts at `start` and each run starts `pitch` after the previous.
+    fn iter_strided_mut(
+        &mut self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &mut Self::Item>;
 }
 
-impl<T> Array2dStorageMut for &mut [T] {
-    fn slice_mut(&mut self) -> &mut [Self::Item] {
-        self
+impl<S: Array2dStorageMut> Array2dStorageSet for S {
+    fn set(&mut self, index: usize, value: Self::Item) -> bool {
+        match self.get_mut(index) {
+            Some(item) => {
+                *item = value;
+                true
+            }
+            None => false,
+        }
     }
 }
 
-impl<T> Array2dStorage for Vec<T> {
-    type Item = T;
+/// A readable storage backed by a contiguous slice.
+pub trait Array2dStorageSlice: Array2dStorageRef {
+    fn slice(&self) -> &[Self::Item];
+}
 
-    fn slice(&self) -> &[Self::Item] {
-        self
-    }
+/// A mutable storage backed by a contiguous slice.
+pub trait Array2dStorageSliceMut: Array2dStorageSlice + Array2dStorageMut {
+    fn slice_mut(&mut self) -> &mut [Self::Item];
 }
 
-impl<T> Array2dStorageMut for Vec<T> {
-    fn slice_mut(&mut self) -> &mut [Self::Item] {
-        self
+/// An owned storage of `GenericArray2d` that can be rebuilt from a [`Vec`].
+pub trait Array2dStorageOwned: Array2dStorageMut + Sized {
+    fn from_vec(vec: Vec<Self::Item>) -> Self;
+    fn into_owned_iter(self) -> impl Iterator<Item = Self::Item>;
+
+    /// Returns the backing [`Vec`] if there is one, enables resizing in place.
+    fn vec_mut(&mut self) -> Option<&mut Vec<Self::Item>> {
+        None
     }
 }
 
-impl<T> Array2dStorageOwned for Vec<T> {
-    fn vec_mut(&mut self) -> &mut Vec<Self::Item> {
-        self
-    }
+/// Iterate through strided runs of a slice.
+pub(crate) fn strided<T>(
+    slice: &[T],
+    start: usize,
+    width: usize,
+    pitch: usize,
+    height: usize,
+) -> impl Iterator<Item = &T> {
+    let slice = slice.get(start..).unwrap_or_default();
+    slice
+        .chunks(pitch.max(1))
+        .take(height)
+        .flat_map(move |row| &row[..width])
+}
+
+/// Iterate through strided runs of a mutable slice.
+pub(crate) fn strided_mut<T>(
+    slice: &mut [T],
+    start: usize,
+    width: usize,
+    pitch: usize,
+    height: usize,
+) -> impl Iterator<Item = &mut T> {
+    let slice = slice.get_mut(start..).unwrap_or_default();
+    slice
+        .chunks_mut(pitch.max(1))
+        .take(height)
+        .flat_map(move |row| &mut row[..width])
+}
+
+macro_rules! impl_slice_storage {
+    ($($ty: ty),*) => {$(
+        impl<T> Array2dStorage for $ty {
+            type Item = T;
+
+            fn len(&self) -> usize {
+                <[T]>::len(self)
+            }
+
+            fn get_cloned(&self, index: usize) -> Option<T>
+            where
+                T: Clone,
+            {
+                <[T]>::get(self, index).cloned()
+            }
+        }
+
+        impl<T> Array2dStorageRef for $ty {
+            fn get(&self, index: usize) -> Option<&Self::Item> {
+                <[T]>::get(self, index)
+            }
+
+            fn iter_strided(
+                &self,
+                start: usize,
+                width: usize,
+                pitch: usize,
+                height: usize,
+            ) -> impl Iterator<Item = &Self::Item> {
+                strided(self, start, width, pitch, height)
+            }
+        }
+
+        impl<T> Array2dStorageSlice for $ty {
+            fn slice(&self) -> &[Self::Item] {
+                self
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_slice_storage_mut {
+    ($($ty: ty),*) => {$(
+        impl<T> Array2dStorageMut for $ty {
+            fn get_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+                <[T]>::get_mut(self, index)
+            }
+
+            fn iter_strided_mut(
+                &mut self,
+                start: usize,
+                width: usize,
+                pitch: usize,
+                height: usize,
+            ) -> impl Iterator<Item = &mut Self::Item> {
+                strided_mut(self, start, width, pitch, height)
+            }
+        }
+
+        impl<T> Array2dStorageSliceMut for $ty {
+            fn slice_mut(&mut self) -> &mut [Self::Item] {
+                self
+            }
+        }
+    )*};
+}
 
+impl_slice_storage!(&[T], &mut [T], Vec<T>);
+impl_slice_storage_mut!(&mut [T], Vec<T>);
+
+impl<T> Array2dStorageOwned for Vec<T> {
     fn from_vec(vec: Vec<Self::Item>) -> Self {
         vec
     }
@@ -64,4 +212,8 @@ impl<T> Array2dStorageOwned for Vec<T> {
     fn into_owned_iter(self) -> impl Iterator<Item = Self::Item> {
         self.into_iter()
     }
+
+    fn vec_mut(&mut self) -> Option<&mut Vec<Self::Item>> {
+        Some(self)
+    }
 }
@@ -0,0 +1,365 @@
+//! Tiled storage with Z-order addressing for cache locality.
+
+use alloc::vec::Vec;
+use mint::Vector2;
+
+use crate::{
+    Array2d, Boundary, GenericArray2d,
+    boundary::IntoBoundary,
+    storage::{Array2dStorage, Array2dStorageMut, Array2dStorageRef},
+    util::*,
+};
+
+/// Width and height of a tile.
+const TILE: usize = 8;
+/// Number of items in a tile.
+const TILE_LEN: usize = TILE * TILE;
+
+/// A 2d array stored in `8x8` tiles, each tile is stored in Z-order (Morton order).
+///
+/// Neighbouring points in both axis are likely to share a cache line,
+/// which benefits algorithms that access points vertically.
+///
+/// Partial tiles on the edges are padded with [`Default::default`].
+/// `iter` visits points in row major order, `iter_tiled` in storage order.
+/// [`as_array`](TiledArray2d::as_array) exposes the array to generic array methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TiledArray2d<T> {
+    data: Vec<T>,
+    boundary: Boundary,
+    /// Number of tiles in a row.
+    tiles_x: usize,
+}
+
+impl<T> Default for TiledArray2d<T> {
+    fn default() -> Self {
+        TiledArray2d {
+            data: Vec::new(),
+            boundary: Boundary::EMPTY,
+            tiles_x: 0,
+        }
+    }
+}
+
+/// Interleave the lower 3 bits of `x` and `y`.
+fn morton(x: usize, y: usize) -> usize {
+    let spread = |v: usize| (v & 1) | ((v & 2) << 1) | ((v & 4) << 2);
+    spread(x) | (spread(y) << 1)
+}
+
+/// Inverse of [`morton`].
+fn demorton(index: usize) -> (usize, usize) {
+    let compact = |v: usize| (v & 1) | ((v >> 1) & 2) | ((v >> 2) & 4);
+    (compact(index), compact(index >> 1))
+}
+
+fn tile_count(boundary: Boundary) -> (usize, usize) {
+    (
+        (boundary.dimension.x as usize).div_ceil(TILE),
+        (boundary.dimension.y as usize).div_ceil(TILE),
+    )
+}
+
+impl<T> TiledArray2d<T> {
+    /// Initialize with a function.
+    pub fn init<U: From<Vector2<i32>>>(
+        boundary: impl IntoBoundary,
+        mut init: impl FnMut(U) -> T,
+    ) -> Self
+    where
+        T: Default,
+    {
+        let boundary = boundary.into_boundary();
+        let (tiles_x, tiles_y) = tile_count(boundary);
+        let mut data = Vec::with_capacity(tiles_x * tiles_y * TILE_LEN);
+        data.extend((0..tiles_x * tiles_y * TILE_LEN).map(|i| {
+            match position_of(boundary, tiles_x, i) {
+                Some(position) => init(position.into()),
+                None => T::default(),
+            }
+        }));
+        TiledArray2d {
+            data,
+            boundary,
+            tiles_x,
+        }
+    }
+
+    /// Initialize with [`Default::default`].
+    pub fn new(boundary: impl IntoBoundary) -> Self
+    where
+        T: Default,
+    {
+        let boundary = boundary.into_boundary();
+        let (tiles_x, tiles_y) = tile_count(boundary);
+        let mut data = Vec::new();
+        data.resize_with(tiles_x * tiles_y * TILE_LEN, Default::default);
+        TiledArray2d {
+            data,
+            boundary,
+            tiles_x,
+        }
+    }
+
+    /// Returns true if contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.boundary.is_empty()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    /// Returns the boundary of the array.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Returns true if a point is in boundary.
+    pub fn contains(&self, position: impl Into<Vector2<i32>>) -> bool {
+        self.boundary.contains(position)
+    }
+
+    fn index_of(&self, position: Vector2<i32>) -> usize {
+        let Vector2 { x, y } = to_local(position, self.boundary.min);
+        index_of(self.tiles_x, x, y)
+    }
+
+    /// Returns a point.
+    pub fn get(&self, position: impl Into<Vector2<i32>>) -> Option<&T> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        self.data.get(self.index_of(position))
+    }
+
+    /// Returns a point.
+    pub fn get_mut(&mut self, position: impl Into<Vector2<i32>>) -> Option<&mut T> {
+        let position = position.into();
+        if !self.boundary.contains(position) {
+            return None;
+        }
+        let index = self.index_of(position);
+        self.data.get_mut(index)
+    }
+
+    /// Try set a position to a value, returns `true` if in boundary.
+    pub fn set(&mut self, position: impl Into<Vector2<i32>>, value: T) -> bool {
+        if let Some(v) = self.get_mut(position) {
+            *v = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Iterate through pairs of points and values in row major order.
+    pub fn iter<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, &T)> {
+        self.boundary
+            .iter::<Vector2<i32>>()
+            .map(|position| (position.into(), &self.data[self.index_of(position)]))
+    }
+
+    /// Iterate through pairs of points and values in row major order.
+    pub fn iter_mut<U: From<Vector2<i32>>>(&mut self) -> impl Iterator<Item = (U, &mut T)> {
+        let (boundary, tiles_x) = (self.boundary, self.tiles_x);
+        let indices = boundary.iter::<Vector2<i32>>().map(move |position| {
+            let Vector2 { x, y } = to_local(position, boundary.min);
+            index_of(tiles_x, x, y)
+        });
+        // SAFETY: Each point in the boundary has its own index.
+        let values = unsafe { disjoint_mut(&mut self.data, indices) };
+        boundary.iter::<U>().zip(values)
+    }
+
+    /// Returns all values in row major order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter::<Vector2<i32>>().map(|(_, v)| v)
+    }
+
+    /// Returns all values in row major order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.iter_mut::<Vector2<i32>>().map(|(_, v)| v)
+    }
+
+    /// Iterate through pairs of points and values in storage order,
+    /// tile by tile with each tile in Z-order.
+    pub fn iter_tiled<U: From<Vector2<i32>>>(&self) -> impl Iterator<Item = (U, &T)> {
+        let (boundary, tiles_x) = (self.boundary, self.tiles_x);
+        self.data.iter().enumerate().filter_map(move |(i, v)| {
+            position_of(boundary, tiles_x, i).map(|position| (position.into(), v))
+        })
+    }
+
+    /// Iterate through pairs of points and values in storage order,
+    /// tile by tile with each tile in Z-order.
+    pub fn iter_tiled_mut<U: From<Vector2<i32>>>(&mut self) -> impl Iterator<Item = (U, &mut T)> {
+        let (boundary, tiles_x) = (self.boundary, self.tiles_x);
+        self.data.iter_mut().enumerate().filter_map(move |(i, v)| {
+            position_of(boundary, tiles_x, i).map(|position| (position.into(), v))
+        })
+    }
+
+    /// Move the origin point of the array without affecting underlying data.
+    pub fn displace(&mut self, by: impl Into<Vector2<i32>>) {
+        self.boundary.displace(by);
+    }
+
+    /// View the array as a row major [`GenericArray2d`],
+    /// which enables generic methods like [`zip`](GenericArray2d::zip) or [`sum`](GenericArray2d::sum).
+    pub fn as_array(&self) -> GenericArray2d<&TiledArray2d<T>> {
+        GenericArray2d {
+            data: self,
+            boundary: self.boundary,
+            pitch: self.boundary.pitch(),
+            offset: 0,
+        }
+    }
+
+    /// View the array as a mutable row major [`GenericArray2d`].
+    pub fn as_array_mut(&mut self) -> GenericArray2d<&mut TiledArray2d<T>> {
+        GenericArray2d {
+            boundary: self.boundary,
+            pitch: self.boundary.pitch(),
+            offset: 0,
+            data: self,
+        }
+    }
+
+    /// Clone into a row major [`Array2d`].
+    pub fn cloned(&self) -> Array2d<T>
+    where
+        T: Clone,
+    {
+        Array2d::from_vec(self.values().cloned().collect(), self.boundary)
+    }
+}
+
+/// Returns the index in the underlying buffer of a local position.
+fn index_of(tiles_x: usize, x: usize, y: usize) -> usize {
+    let tile = (y / TILE) * tiles_x + x / TILE;
+    tile * TILE_LEN + morton(x % TILE, y % TILE)
+}
+
+/// Returns the index in the underlying buffer of a row major index in the boundary.
+fn locate_index(boundary: Boundary, tiles_x: usize, index: usize) -> Option<usize> {
+    if index >= boundary.len() {
+        return None;
+    }
+    let width = boundary.pitch();
+    Some(index_of(tiles_x, index % width, index / width))
+}
+
+/// Iterate through mutable references to items by index.
+///
+/// # Safety
+///
+/// `indices` must not repeat.
+unsafe fn disjoint_mut<T>(
+    data: &mut [T],
+    indices: impl Iterator<Item = usize>,
+) -> impl Iterator<Item = &mut T> {
+    let (ptr, len) = (data.as_mut_ptr(), data.len());
+    indices.map(move |index| {
+        assert!(index < len, "Index out of bounds.");
+        // SAFETY: In bounds, and the caller guarantees no other reference to the item exists.
+        unsafe { &mut *ptr.add(index) }
+    })
+}
+
+/// Returns the position of an index in the underlying buffer, returns `None` for padding.
+fn position_of(boundary: Boundary, tiles_x: usize, index: usize) -> Option<Vector2<i32>> {
+    let tile = index / TILE_LEN;
+    let (x, y) = demorton(index % TILE_LEN);
+    let x = (tile % tiles_x) * TILE + x;
+    let y = (tile / tiles_x) * TILE + y;
+    // Padding may lie past the coordinate range, only convert points in bounds.
+    (x < boundary.dimension.x as usize && y < boundary.dimension.y as usize)
+        .then(|| from_local(boundary.min, x, y))
+}
+
+impl<T: Default> From<Array2d<T>> for TiledArray2d<T> {
+    fn from(value: Array2d<T>) -> Self {
+        let mut result = TiledArray2d::new(value.boundary());
+        for (position, item) in value.iter_owned::<Vector2<i32>>() {
+            result.set(position, item);
+        }
+        result
+    }
+}
+
+impl<T: Default> From<TiledArray2d<T>> for Array2d<T> {
+    fn from(mut value: TiledArray2d<T>) -> Self {
+        let boundary = value.boundary;
+        Array2d::from_vec(value.values_mut().map(core::mem::take).collect(), boundary)
+    }
+}
+
+impl<T> Array2dStorage for &TiledArray2d<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        Array2dStorageRef::get(self, index).cloned()
+    }
+}
+
+impl<T> Array2dStorageRef for &TiledArray2d<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.data
+            .get(locate_index(self.boundary, self.tiles_x, index)?)
+    }
+}
+
+impl<T> Array2dStorage for &mut TiledArray2d<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.boundary.len()
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        Array2dStorageRef::get(self, index).cloned()
+    }
+}
+
+impl<T> Array2dStorageRef for &mut TiledArray2d<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.data
+            .get(locate_index(self.boundary, self.tiles_x, index)?)
+    }
+}
+
+impl<T> Array2dStorageMut for &mut TiledArray2d<T> {
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let index = locate_index(self.boundary, self.tiles_x, index)?;
+        self.data.get_mut(index)
+    }
+
+    fn iter_strided_mut(
+        &mut self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &mut T> {
+        assert!(width <= pitch || height <= 1, "Runs must not overlap.");
+        let (boundary, tiles_x) = (self.boundary, self.tiles_x);
+        let indices = (0..height)
+            .flat_map(move |y| (0..width).map(move |x| start + y * pitch + x))
+            .map(move |i| locate_index(boundary, tiles_x, i).expect("Index out of bounds."));
+        // SAFETY: Runs do not overlap, so every index is distinct.
+        unsafe { disjoint_mut(&mut self.data, indices) }
+    }
+}
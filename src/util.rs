@@ -1,9 +1,11 @@
-use std::mem;
+use core::mem;
 
-use mint::Vector2;
+use mint::{Vector2, Vector3};
+
+use crate::coord::Coordinate;
 
 #[track_caller]
-pub(crate) fn add(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
+pub(crate) fn add<C: Coordinate>(left: Vector2<C>, right: Vector2<C>) -> Vector2<C> {
     Vector2 {
         x: left.x + right.x,
         y: left.y + right.y,
@@ -11,15 +13,15 @@ pub(crate) fn add(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
 }
 
 #[track_caller]
-pub(crate) fn addu(left: Vector2<i32>, right: Vector2<u32>) -> Vector2<i32> {
+pub(crate) fn addu<C: Coordinate>(left: Vector2<C>, right: Vector2<C::Unsigned>) -> Vector2<C> {
     Vector2 {
-        x: left.x.wrapping_add(right.x as i32),
-        y: left.y.wrapping_add(right.y as i32),
+        x: left.x.wrapping_add_unsigned(right.x),
+        y: left.y.wrapping_add_unsigned(right.y),
     }
 }
 
 #[track_caller]
-pub(crate) fn sub(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
+pub(crate) fn sub<C: Coordinate>(left: Vector2<C>, right: Vector2<C>) -> Vector2<C> {
     Vector2 {
         x: left.x - right.x,
         y: left.y - right.y,
@@ -27,7 +29,7 @@ pub(crate) fn sub(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
 }
 
 #[track_caller]
-pub(crate) fn vec_min(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
+pub(crate) fn vec_min<C: Coordinate>(left: Vector2<C>, right: Vector2<C>) -> Vector2<C> {
     Vector2 {
         x: left.x.min(right.x),
         y: left.y.min(right.y),
@@ -35,7 +37,7 @@ pub(crate) fn vec_min(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
 }
 
 #[track_caller]
-pub(crate) fn vec_max(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
+pub(crate) fn vec_max<C: Coordinate>(left: Vector2<C>, right: Vector2<C>) -> Vector2<C> {
     Vector2 {
         x: left.x.max(right.x),
         y: left.y.max(right.y),
@@ -43,34 +45,102 @@ pub(crate) fn vec_max(left: Vector2<i32>, right: Vector2<i32>) -> Vector2<i32> {
 }
 
 #[track_caller]
-pub(crate) fn u2i(v: Vector2<u32>) -> Vector2<i32> {
+pub(crate) fn u2i<C: Coordinate>(v: Vector2<C::Unsigned>) -> Vector2<C> {
     Vector2 {
-        x: v.x as i32,
-        y: v.y as i32,
+        x: C::cast_signed(v.x),
+        y: C::cast_signed(v.y),
     }
 }
 
 #[track_caller]
-pub(crate) fn i2u(v: Vector2<i32>) -> Vector2<u32> {
+pub(crate) fn i2u<C: Coordinate>(v: Vector2<C>) -> Vector2<C::Unsigned> {
     Vector2 {
-        x: v.x.max(0) as u32,
-        y: v.y.max(0) as u32,
+        x: v.x.max(C::ZERO).cast_unsigned(),
+        y: v.y.max(C::ZERO).cast_unsigned(),
     }
 }
 
 #[track_caller]
-pub(crate) fn abs(v: Vector2<i32>) -> Vector2<i32> {
+pub(crate) fn abs<C: Coordinate>(v: Vector2<C>) -> Vector2<C> {
     Vector2 {
         x: v.x.abs(),
         y: v.y.abs(),
     }
 }
 
+/// Subtract without overflow.
+pub(crate) fn wide_sub<C: Coordinate>(left: Vector2<C>, right: Vector2<C>) -> Vector2<i128> {
+    Vector2 {
+        x: left.x.to_i128() - right.x.to_i128(),
+        y: left.y.to_i128() - right.y.to_i128(),
+    }
+}
+
+/// Returns `[1, 1]`.
+pub(crate) fn one<C: Coordinate>() -> Vector2<C> {
+    Vector2 {
+        x: C::ONE,
+        y: C::ONE,
+    }
+}
+
+/// Converts a local position in usize to a world position, wraps on overflow.
+pub(crate) fn from_local<C: Coordinate>(origin: Vector2<C>, x: usize, y: usize) -> Vector2<C> {
+    addu(
+        origin,
+        Vector2 {
+            x: C::unsigned_from_usize(x),
+            y: C::unsigned_from_usize(y),
+        },
+    )
+}
+
+/// Converts a world position to a local position, `pos` must not be less than `origin`.
+pub(crate) fn to_local<C: Coordinate>(pos: Vector2<C>, origin: Vector2<C>) -> Vector2<usize> {
+    // Differences may not fit in `C`, but always fit in the unsigned counterpart.
+    Vector2 {
+        x: C::unsigned_to_usize(pos.x.wrapping_sub(origin.x).cast_unsigned()),
+        y: C::unsigned_to_usize(pos.y.wrapping_sub(origin.y).cast_unsigned()),
+    }
+}
+
+#[track_caller]
+pub(crate) fn transpose<T>(v: Vector2<T>) -> Vector2<T> {
+    Vector2 { x: v.y, y: v.x }
+}
+
+#[track_caller]
+pub(crate) fn add3(left: Vector3<i32>, right: Vector3<i32>) -> Vector3<i32> {
+    Vector3 {
+        x: left.x + right.x,
+        y: left.y + right.y,
+        z: left.z + right.z,
+    }
+}
+
+#[track_caller]
+pub(crate) fn sub3(left: Vector3<i32>, right: Vector3<i32>) -> Vector3<i32> {
+    Vector3 {
+        x: left.x - right.x,
+        y: left.y - right.y,
+        z: left.z - right.z,
+    }
+}
+
+#[track_caller]
+pub(crate) fn addu3(left: Vector3<i32>, right: Vector3<u32>) -> Vector3<i32> {
+    Vector3 {
+        x: left.x.wrapping_add(right.x as i32),
+        y: left.y.wrapping_add(right.y as i32),
+        z: left.z.wrapping_add(right.z as i32),
+    }
+}
+
 #[inline]
 #[track_caller]
-pub fn offset_of(pos: Vector2<i32>, origin: Vector2<i32>, pitch: usize) -> usize {
-    let pos = sub(pos, origin);
-    pos.y as usize * pitch + pos.x as usize
+pub fn offset_of<C: Coordinate>(pos: Vector2<C>, origin: Vector2<C>, pitch: usize) -> usize {
+    let pos = to_local(pos, origin);
+    pos.y * pitch + pos.x
 }
 
 #[inline]
@@ -88,52 +158,58 @@ pub fn move_within<T: Default>(slice: &mut [T], from: usize, to: usize, len: usi
     }
 }
 
-pub struct DimensionIter {
-    position: Vector2<u32>,
-    dimension: Vector2<u32>,
+/// Iterate through local positions of a dimension in row major order,
+/// convert to world positions with [`addu`].
+pub struct DimensionIter<C: Coordinate> {
+    position: Vector2<C::Unsigned>,
+    dimension: Vector2<C::Unsigned>,
 }
 
-impl DimensionIter {
-    pub fn new(dimension: Vector2<u32>) -> Self {
+impl<C: Coordinate> DimensionIter<C> {
+    pub fn new(dimension: Vector2<C::Unsigned>) -> Self {
         DimensionIter {
-            position: Vector2 { x: 0, y: 0 },
+            position: Vector2 {
+                x: C::UNSIGNED_ZERO,
+                y: C::UNSIGNED_ZERO,
+            },
             dimension,
         }
     }
 }
 
-impl Iterator for DimensionIter {
-    type Item = Vector2<i32>;
+impl<C: Coordinate> Iterator for DimensionIter<C> {
+    type Item = Vector2<C::Unsigned>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let out = self.position;
         if self.position.y >= self.dimension.y {
             return None;
         }
-        self.position.x += 1;
+        self.position.x = self.position.x + C::UNSIGNED_ONE;
         if self.position.x >= self.dimension.x {
-            self.position.x = 0;
-            self.position.y += 1;
+            self.position.x = C::UNSIGNED_ZERO;
+            self.position.y = self.position.y + C::UNSIGNED_ONE;
         }
-        Some(u2i(out))
+        Some(out)
     }
 }
 
+/// Iterate through owned items and their local positions, skipping padding items in `pitch`.
 pub(crate) struct IterOwned<T> {
     pub iter: T,
-    pub position: Vector2<u32>,
-    pub dimension: Vector2<u32>,
-    pub pitch: u32,
+    pub position: Vector2<usize>,
+    pub dimension: Vector2<usize>,
+    pub pitch: usize,
 }
 
 impl<T: Iterator> Iterator for IterOwned<T> {
-    type Item = (Vector2<i32>, T::Item);
+    type Item = (Vector2<usize>, T::Item);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.position.y >= self.dimension.y {
             return None;
         }
-        let out = (u2i(self.position), self.iter.next()?);
+        let out = (self.position, self.iter.next()?);
         self.position.x += 1;
         if self.position.x >= self.dimension.x {
             self.position.x = 0;
@@ -1,40 +1,134 @@
 //! Operations on arrays with the same dimension.
 
 use crate::{
-    Array2d, Boundary, GenericArray2d,
-    storage::{Array2dStorage, Array2dStorageMut},
+    Coordinate, GenericArray2d, GenericBoundary,
+    storage::{Array2dStorageRef, Array2dStorageSliceMut},
     util::*,
 };
+use alloc::vec::Vec;
 use mint::Vector2;
 
 /// Unifies `&GenericArray2d` and `&mut GenericArray2d` into the same interface for [`Zip`].
 pub trait GenericArray2dRef {
+    type Coord: Coordinate;
     type Row<'t>: IntoIterator
     where
         Self: 't;
     type RowMut<'t>: IntoIterator
     where
         Self: 't;
-    fn min(&self) -> Vector2<i32>;
-    fn dimension(&self) -> Vector2<u32>;
+    fn min(&self) -> Vector2<Self::Coord>;
+    fn dimension(&self) -> Vector2<<Self::Coord as Coordinate>::Unsigned>;
     fn rows(&self) -> impl Iterator<Item = Self::Row<'_>>;
     fn rows_mut(&mut self) -> impl Iterator<Item = Self::RowMut<'_>>;
 }
 
-impl<T: Array2dStorage> GenericArray2dRef for &GenericArray2d<T> {
+/// Iterator over references to a row of a [`GenericArray2d`],
+/// used by [`Zip`] on storages that are not contiguous.
+pub struct RowIter<'t, S: Array2dStorageRef> {
+    storage: &'t S,
+    index: usize,
+    end: usize,
+}
+
+impl<'t, S: Array2dStorageRef> Iterator for RowIter<'t, S> {
+    type Item = &'t S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.index += 1;
+        self.storage.get(self.index - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end.saturating_sub(self.index);
+        (len, Some(len))
+    }
+}
+
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2d<T, C> {
+    /// Returns rows as iterators of references, works on storages that are not contiguous.
+    pub(crate) fn row_iters(&self) -> impl Iterator<Item = RowIter<'_, T>> {
+        let (width, pitch, offset) = (self.width(), self.pitch, self.offset);
+        (0..self.height()).map(move |y| RowIter {
+            storage: &self.data,
+            index: offset + y * pitch,
+            end: offset + y * pitch + width,
+        })
+    }
+}
+
+impl<T: Array2dStorageRef, C: Coordinate> GenericArray2dRef for &GenericArray2d<T, C> {
+    type Coord = C;
     type Row<'t>
-        = &'t [T::Item]
+        = RowIter<'t, T>
     where
         Self: 't;
     type RowMut<'t>
+        = RowIter<'t, T>
+    where
+        Self: 't;
+    fn min(&self) -> Vector2<C> {
+        self.boundary.min
+    }
+
+    fn dimension(&self) -> Vector2<C::Unsigned> {
+        self.boundary.dimension
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Self::Row<'_>> {
+        GenericArray2d::row_iters(self)
+    }
+
+    fn rows_mut(&mut self) -> impl Iterator<Item = Self::RowMut<'_>> {
+        GenericArray2d::row_iters(self)
+    }
+}
+
+impl<T: Array2dStorageSliceMut, C: Coordinate> GenericArray2dRef for &mut GenericArray2d<T, C> {
+    type Coord = C;
+    type Row<'t>
         = &'t [T::Item]
     where
         Self: 't;
-    fn min(&self) -> Vector2<i32> {
+    type RowMut<'t>
+        = &'t mut [T::Item]
+    where
+        Self: 't;
+    fn min(&self) -> Vector2<C> {
+        self.boundary.min
+    }
+
+    fn dimension(&self) -> Vector2<C::Unsigned> {
+        self.boundary.dimension
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Self::Row<'_>> {
+        GenericArray2d::rows(self)
+    }
+
+    fn rows_mut(&mut self) -> impl Iterator<Item = Self::RowMut<'_>> {
+        GenericArray2d::rows_mut(self)
+    }
+}
+
+impl<T, C: Coordinate> GenericArray2dRef for GenericArray2d<&[T], C> {
+    type Coord = C;
+    type Row<'t>
+        = &'t [T]
+    where
+        Self: 't;
+    type RowMut<'t>
+        = &'t [T]
+    where
+        Self: 't;
+    fn min(&self) -> Vector2<C> {
         self.boundary.min
     }
 
-    fn dimension(&self) -> Vector2<u32> {
+    fn dimension(&self) -> Vector2<C::Unsigned> {
         self.boundary.dimension
     }
 
@@ -47,20 +141,21 @@ impl<T: Array2dStorage> GenericArray2dRef for &GenericArray2d<T> {
     }
 }
 
-impl<T: Array2dStorageMut> GenericArray2dRef for &mut GenericArray2d<T> {
+impl<T, C: Coordinate> GenericArray2dRef for GenericArray2d<&mut [T], C> {
+    type Coord = C;
     type Row<'t>
-        = &'t [T::Item]
+        = &'t [T]
     where
         Self: 't;
     type RowMut<'t>
-        = &'t mut [T::Item]
+        = &'t mut [T]
     where
         Self: 't;
-    fn min(&self) -> Vector2<i32> {
+    fn min(&self) -> Vector2<C> {
         self.boundary.min
     }
 
-    fn dimension(&self) -> Vector2<u32> {
+    fn dimension(&self) -> Vector2<C::Unsigned> {
         self.boundary.dimension
     }
 
@@ -73,27 +168,36 @@ impl<T: Array2dStorageMut> GenericArray2dRef for &mut GenericArray2d<T> {
     }
 }
 
-impl<A: Array2dStorage<Item: PartialEq<B::Item>>, B: Array2dStorage> PartialEq<GenericArray2d<B>>
-    for GenericArray2d<A>
+impl<A: Array2dStorageRef<Item: PartialEq<B::Item>>, B: Array2dStorageRef, C: Coordinate>
+    PartialEq<GenericArray2d<B, C>> for GenericArray2d<A, C>
 {
-    fn eq(&self, other: &GenericArray2d<B>) -> bool {
-        self.boundary == other.boundary && self.rows().zip(other.rows()).all(|(a, b)| a == b)
+    fn eq(&self, other: &GenericArray2d<B, C>) -> bool {
+        self.boundary == other.boundary && self.values().zip(other.values()).all(|(a, b)| a == b)
     }
 }
 
-impl<T: Array2dStorage<Item: Eq>> Eq for GenericArray2d<T> {}
+impl<T: Array2dStorageRef<Item: Eq>, C: Coordinate> Eq for GenericArray2d<T, C> {}
 
-impl<T: Array2dStorage<Item: Eq>> GenericArray2d<T> {
+impl<T: Array2dStorageRef<Item: Eq>, C: Coordinate> GenericArray2d<T, C> {
     /// Returns true if dimension and underlying data are equal, ignores the origin points.
-    pub fn equivalent<U: Array2dStorage>(&self, other: &GenericArray2d<U>) -> bool
+    pub fn equivalent<U: Array2dStorageRef>(&self, other: &GenericArray2d<U, C>) -> bool
     where
         T::Item: PartialEq<U::Item>,
     {
         self.boundary.dimension == other.boundary.dimension
-            && self.rows().zip(other.rows()).all(|(a, b)| a == b)
+            && self.values().zip(other.values()).all(|(a, b)| a == b)
     }
 }
 
+/// Dimension of an array, comparable between different coordinate types.
+fn dimension<T: GenericArray2dRef>(array: &T) -> [i128; 2] {
+    let dimension = array.dimension();
+    [
+        T::Coord::unsigned_to_i128(dimension.x),
+        T::Coord::unsigned_to_i128(dimension.y),
+    ]
+}
+
 /// Zipped references of 2d arrays of the same dimension.
 ///
 /// Supports `&array` or `&mut array` only if underlying data is mutable.
@@ -111,12 +215,12 @@ impl<A: GenericArray2dRef, B: GenericArray2dRef> Zip<A, B> {
     ///
     /// This is required for all operations on this type.
     pub fn is_valid(&self) -> bool {
-        self.0.dimension() == self.1.dimension()
+        dimension(&self.0) == dimension(&self.1)
     }
 
     /// Returns false and has no effect if the arrays do not have equal dimension.
     pub fn for_each<'t>(&'t self, mut f: impl FnMut(Item<'t, A>, Item<'t, B>)) -> bool {
-        if self.0.dimension() != self.1.dimension() {
+        if dimension(&self.0) != dimension(&self.1) {
             return false;
         }
         for (row_0, row_1) in self.0.rows().zip(self.1.rows()) {
@@ -132,7 +236,7 @@ impl<A: GenericArray2dRef, B: GenericArray2dRef> Zip<A, B> {
         &'t mut self,
         mut f: impl FnMut(ItemMut<'t, A>, ItemMut<'t, B>),
     ) -> bool {
-        if self.0.dimension() != self.1.dimension() {
+        if dimension(&self.0) != dimension(&self.1) {
             return false;
         }
         for (row_0, row_1) in self.0.rows_mut().zip(self.1.rows_mut()) {
@@ -144,44 +248,52 @@ impl<A: GenericArray2dRef, B: GenericArray2dRef> Zip<A, B> {
     }
 
     /// Returns false and has no effect if the arrays do not have equal dimension.
-    pub fn for_each_indexed<'t, I: From<Vector2<i32>>>(
+    pub fn for_each_indexed<'t, I: From<Vector2<A::Coord>>>(
         &'t self,
         mut f: impl FnMut(I, Item<'t, A>, I, Item<'t, B>),
-    ) -> bool {
-        if self.0.dimension() != self.1.dimension() {
+    ) -> bool
+    where
+        B: GenericArray2dRef<Coord = A::Coord>,
+    {
+        if dimension(&self.0) != dimension(&self.1) {
             return false;
         }
         let min0 = self.0.min();
         let min1 = self.1.min();
-        for (x, (row_0, row_1)) in self.0.rows().zip(self.1.rows()).enumerate() {
-            for (y, (a, b)) in row_0.into_iter().zip(row_1).enumerate() {
-                let pos = Vector2 {
-                    x: x as i32,
-                    y: y as i32,
-                };
-                f(add(pos, min0).into(), a, add(pos, min1).into(), b);
+        for (y, (row_0, row_1)) in self.0.rows().zip(self.1.rows()).enumerate() {
+            for (x, (a, b)) in row_0.into_iter().zip(row_1).enumerate() {
+                f(
+                    from_local(min0, x, y).into(),
+                    a,
+                    from_local(min1, x, y).into(),
+                    b,
+                );
             }
         }
         true
     }
 
     /// Returns false and has no effect if the arrays do not have equal dimension.
-    pub fn for_each_indexed_mut<'t, I: From<Vector2<i32>>>(
+    pub fn for_each_indexed_mut<'t, I: From<Vector2<A::Coord>>>(
         &'t mut self,
         mut f: impl FnMut(I, ItemMut<'t, A>, I, ItemMut<'t, B>),
-    ) -> bool {
-        if self.0.dimension() != self.1.dimension() {
+    ) -> bool
+    where
+        B: GenericArray2dRef<Coord = A::Coord>,
+    {
+        if dimension(&self.0) != dimension(&self.1) {
             return false;
         }
         let min0 = self.0.min();
         let min1 = self.1.min();
-        for (x, (row_0, row_1)) in self.0.rows_mut().zip(self.1.rows_mut()).enumerate() {
-            for (y, (a, b)) in row_0.into_iter().zip(row_1).enumerate() {
-                let pos = Vector2 {
-                    x: x as i32,
-                    y: y as i32,
-                };
-                f(add(pos, min0).into(), a, add(pos, min1).into(), b);
+        for (y, (row_0, row_1)) in self.0.rows_mut().zip(self.1.rows_mut()).enumerate() {
+            for (x, (a, b)) in row_0.into_iter().zip(row_1).enumerate() {
+                f(
+                    from_local(min0, x, y).into(),
+                    a,
+                    from_local(min1, x, y).into(),
+                    b,
+                );
             }
         }
         true
@@ -193,25 +305,31 @@ impl<A: GenericArray2dRef, B: GenericArray2dRef> Zip<A, B> {
     ///
     /// If dimension mismatch.
     #[track_caller]
-    pub fn map<'t, U>(&'t self, mut f: impl FnMut(Item<'t, A>, Item<'t, B>) -> U) -> Array2d<U> {
-        if self.0.dimension() != self.1.dimension() {
+    pub fn map<'t, U>(
+        &'t self,
+        mut f: impl FnMut(Item<'t, A>, Item<'t, B>) -> U,
+    ) -> GenericArray2d<Vec<U>, A::Coord> {
+        if dimension(&self.0) != dimension(&self.1) {
             panic!("Dimension mismatch!");
         }
         let dimension = self.0.dimension();
-        let mut result = Vec::with_capacity((dimension.x * dimension.y) as usize);
+        let mut result = Vec::with_capacity(
+            A::Coord::unsigned_to_usize(dimension.x) * A::Coord::unsigned_to_usize(dimension.y),
+        );
         for (row_0, row_1) in self.0.rows().zip(self.1.rows()) {
             for (a, b) in row_0.into_iter().zip(row_1) {
                 result.push(f(a, b))
             }
         }
-        let boundary = Boundary {
+        let boundary = GenericBoundary {
             min: self.0.min(),
             dimension,
         };
-        Array2d {
+        GenericArray2d {
             data: result,
             boundary,
             pitch: boundary.pitch(),
+            offset: 0,
         }
     }
 
@@ -224,25 +342,209 @@ impl<A: GenericArray2dRef, B: GenericArray2dRef> Zip<A, B> {
     pub fn map_mut<'t, U>(
         &'t mut self,
         mut f: impl FnMut(ItemMut<'t, A>, ItemMut<'t, B>) -> U,
-    ) -> Array2d<U> {
-        if self.0.dimension() != self.1.dimension() {
+    ) -> GenericArray2d<Vec<U>, A::Coord> {
+        if dimension(&self.0) != dimension(&self.1) {
             panic!("Dimension mismatch!");
         }
         let dimension = self.0.dimension();
-        let boundary = Boundary {
+        let boundary = GenericBoundary {
             min: self.0.min(),
             dimension,
         };
-        let mut result = Vec::with_capacity((dimension.x * dimension.y) as usize);
+        let mut result = Vec::with_capacity(
+            A::Coord::unsigned_to_usize(dimension.x) * A::Coord::unsigned_to_usize(dimension.y),
+        );
         for (row_0, row_1) in self.0.rows_mut().zip(self.1.rows_mut()) {
             for (a, b) in row_0.into_iter().zip(row_1) {
                 result.push(f(a, b))
             }
         }
-        Array2d {
+        GenericArray2d {
             data: result,
             boundary,
             pitch: boundary.pitch(),
+            offset: 0,
         }
     }
 }
+
+macro_rules! impl_zip_n {
+    ($name: ident, $doc: literal, $($ty: ident $idx: tt $var: ident),*) => {
+        #[doc = $doc]
+        ///
+        /// Supports `&array` or `&mut array` only if underlying data is mutable.
+        ///
+        /// # Constraints
+        ///
+        /// Dimensions of all arrays must match, origin points are not considered.
+        pub struct $name<$($ty: GenericArray2dRef),*>($(pub $ty),*);
+
+        impl<$($ty: GenericArray2dRef),*> $name<$($ty),*> {
+            /// Returns true if size matches.
+            ///
+            /// This is required for all operations on this type.
+            pub fn is_valid(&self) -> bool {
+                let first = dimension(&self.0);
+                $(dimension(&self.$idx) == first)&&*
+            }
+
+            /// Returns false and has no effect if the arrays do not have equal dimension.
+            pub fn for_each<'t>(&'t self, mut f: impl FnMut($(Item<'t, $ty>),*)) -> bool {
+                if !self.is_valid() {
+                    return false;
+                }
+                let mut rows = ($(self.$idx.rows(),)*);
+                while let ($(Some($var),)*) = ($(rows.$idx.next(),)*) {
+                    let mut items = ($($var.into_iter(),)*);
+                    while let ($(Some($var),)*) = ($(items.$idx.next(),)*) {
+                        f($($var),*)
+                    }
+                }
+                true
+            }
+
+            /// Returns false and has no effect if the arrays do not have equal dimension.
+            pub fn for_each_mut<'t>(&'t mut self, mut f: impl FnMut($(ItemMut<'t, $ty>),*)) -> bool {
+                if !self.is_valid() {
+                    return false;
+                }
+                let mut rows = ($(self.$idx.rows_mut(),)*);
+                while let ($(Some($var),)*) = ($(rows.$idx.next(),)*) {
+                    let mut items = ($($var.into_iter(),)*);
+                    while let ($(Some($var),)*) = ($(items.$idx.next(),)*) {
+                        f($($var),*)
+                    }
+                }
+                true
+            }
+
+            /// Returns false and has no effect if the arrays do not have equal dimension.
+            pub fn for_each_indexed<'t, C: Coordinate, I: From<Vector2<C>>>(
+                &'t self,
+                mut f: impl FnMut($(I, Item<'t, $ty>),*),
+            ) -> bool
+            where
+                $($ty: GenericArray2dRef<Coord = C>),*
+            {
+                if !self.is_valid() {
+                    return false;
+                }
+                let mins = ($(self.$idx.min(),)*);
+                let mut rows = ($(self.$idx.rows(),)*);
+                let mut y = 0;
+                while let ($(Some($var),)*) = ($(rows.$idx.next(),)*) {
+                    let mut items = ($($var.into_iter(),)*);
+                    let mut x = 0;
+                    while let ($(Some($var),)*) = ($(items.$idx.next(),)*) {
+                        f($(from_local(mins.$idx, x, y).into(), $var),*);
+                        x += 1;
+                    }
+                    y += 1;
+                }
+                true
+            }
+
+            /// Returns false and has no effect if the arrays do not have equal dimension.
+            pub fn for_each_indexed_mut<'t, C: Coordinate, I: From<Vector2<C>>>(
+                &'t mut self,
+                mut f: impl FnMut($(I, ItemMut<'t, $ty>),*),
+            ) -> bool
+            where
+                $($ty: GenericArray2dRef<Coord = C>),*
+            {
+                if !self.is_valid() {
+                    return false;
+                }
+                let mins = ($(self.$idx.min(),)*);
+                let mut rows = ($(self.$idx.rows_mut(),)*);
+                let mut y = 0;
+                while let ($(Some($var),)*) = ($(rows.$idx.next(),)*) {
+                    let mut items = ($($var.into_iter(),)*);
+                    let mut x = 0;
+                    while let ($(Some($var),)*) = ($(items.$idx.next(),)*) {
+                        f($(from_local(mins.$idx, x, y).into(), $var),*);
+                        x += 1;
+                    }
+                    y += 1;
+                }
+                true
+            }
+
+            /// Create a new array by combining all arrays, inheriting the position of the first array.
+            ///
+            /// # Panics
+            ///
+            /// If dimension mismatch.
+            #[track_caller]
+            pub fn map<'t, U>(&'t self, mut f: impl FnMut($(Item<'t, $ty>),*) -> U) -> GenericArray2d<Vec<U>, S0::Coord> {
+                if !self.is_valid() {
+                    panic!("Dimension mismatch!");
+                }
+                let boundary = GenericBoundary {
+                    min: self.0.min(),
+                    dimension: self.0.dimension(),
+                };
+                let mut result = Vec::with_capacity(boundary.len());
+                self.for_each(|$($var),*| result.push(f($($var),*)));
+                GenericArray2d {
+                    data: result,
+                    boundary,
+                    pitch: boundary.pitch(),
+                    offset: 0,
+                }
+            }
+
+            /// Create a new array by combining all arrays, inheriting the position of the first array.
+            ///
+            /// # Panics
+            ///
+            /// If dimension mismatch.
+            #[track_caller]
+            pub fn map_mut<'t, U>(
+                &'t mut self,
+                mut f: impl FnMut($(ItemMut<'t, $ty>),*) -> U,
+            ) -> GenericArray2d<Vec<U>, S0::Coord> {
+                if !self.is_valid() {
+                    panic!("Dimension mismatch!");
+                }
+                let boundary = GenericBoundary {
+                    min: self.0.min(),
+                    dimension: self.0.dimension(),
+                };
+                let mut result = Vec::with_capacity(boundary.len());
+                self.for_each_mut(|$($var),*| result.push(f($($var),*)));
+                GenericArray2d {
+                    data: result,
+                    boundary,
+                    pitch: boundary.pitch(),
+                    offset: 0,
+                }
+            }
+        }
+    };
+}
+
+impl_zip_n!(
+    Zip3,
+    "Zipped references of 3 2d arrays of the same dimension.",
+    S0 0 a, S1 1 b, S2 2 c
+);
+impl_zip_n!(
+    Zip4,
+    "Zipped references of 4 2d arrays of the same dimension.",
+    S0 0 a, S1 1 b, S2 2 c, S3 3 d
+);
+
+impl<A: GenericArray2dRef, B: GenericArray2dRef> Zip<A, B> {
+    /// Add another array to the zip.
+    pub fn and<S: GenericArray2dRef>(self, array: S) -> Zip3<A, B, S> {
+        Zip3(self.0, self.1, array)
+    }
+}
+
+impl<S0: GenericArray2dRef, S1: GenericArray2dRef, S2: GenericArray2dRef> Zip3<S0, S1, S2> {
+    /// Add another array to the zip.
+    pub fn and<S3: GenericArray2dRef>(self, array: S3) -> Zip4<S0, S1, S2, S3> {
+        Zip4(self.0, self.1, self.2, array)
+    }
+}
@@ -77,4 +77,12 @@ pub fn serde() {
             ],
         })
     );
+
+    let e = serde_json::from_value::<Array2d<i32>>(json!({
+        "min": [0, 0],
+        "dimension": [2, 2],
+        "data": [1, 2, 3],
+    }))
+    .unwrap_err();
+    assert_eq!(e.to_string(), "Not enough items, needed 4, got 3.");
 }
@@ -1,6 +1,10 @@
 use std::fmt::Debug;
 
-use garray2d::{Array2d, Boundary};
+use garray2d::{
+    Anchor, Array2d, Array2dError, Array3d, Boundary, Boundary3, ColumnMajorArray2d, FixedArray2d,
+    GenericArray2d, GenericBoundary, Layered, Mask2d, QuadTree2d, RleArray2d, ScrollingArray2d,
+    SharedArray2d, TiledArray2d, Zip3, Zip4, traits,
+};
 use glam::IVec2;
 
 #[track_caller]
@@ -376,6 +380,22 @@ pub fn expand() {
     );
 }
 
+#[test]
+pub fn resize_containing_empty() {
+    // Empty boundaries are ignored instead of growing the array towards their `min`.
+    let mut a = Array2d::from_vec(vec![1, 2, 3, 4], [5, 5]..=[6, 6]);
+    a.resize_containing(Boundary::EMPTY);
+    assert_eq!(a.boundary(), Boundary::min_max([5, 5], [6, 6]));
+    a.merge(&Array2d::<i32>::new(Boundary::min_dim([-3, -3], [0, 2])));
+    assert_eq!(a.boundary(), Boundary::min_max([5, 5], [6, 6]));
+    iter_eq(a.rows(), [&[1, 2], &[3, 4]] as [&[_]; 2]);
+
+    let mut a = Array2d::<i32>::default();
+    a.merge(&Array2d::from_vec(vec![1, 2], [5, 5]..=[6, 5]));
+    assert_eq!(a.boundary(), Boundary::min_max([5, 5], [6, 5]));
+    iter_eq(a.rows(), [&[1, 2]] as [&[_]; 1]);
+}
+
 #[test]
 pub fn zip() {
     let mut a = Array2d::from_vec(vec![9, 4, 7, 3, 6, 1, 2, 8, 5], [0, 0]..=[2, 2]);
@@ -432,3 +452,1540 @@ pub fn owned_pitched() {
     assert_eq!(arr.pitch(), 4);
     assert_eq!(arr.underlying_slice().len(), 8);
 }
+
+#[test]
+pub fn rows_mut() {
+    let mut arr = Array2d::from_vec_pitch((0..12).collect(), [2, 3], 4);
+    assert_eq!(arr.rows_mut().count(), 3);
+    for row in arr.rows_mut() {
+        row[0] = -1;
+    }
+    iter_eq(arr.values().copied(), [-1, 1, -1, 5, -1, 9]);
+
+    let mut arr = Array2d::from_vec_pitch((0..12).collect(), [3, 2], 4);
+    assert_eq!(arr.rows_mut().count(), 2);
+    iter_eq(arr.rows_mut().map(|row| row.len()), [3, 3]);
+}
+
+#[test]
+pub fn copy_within() {
+    let mut arr = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], [0, 0]..=[2, 2]);
+    arr.copy_within([0, 0]..=[1, 1], [1, 1]);
+    iter_eq(
+        arr.rows(),
+        [&[1, 2, 3], &[4, 1, 2], &[7, 4, 5]] as [&[_]; 3],
+    );
+
+    let mut arr = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], [0, 0]..=[2, 2]);
+    arr.copy_within([1, 1]..=[2, 2], [0, 0]);
+    iter_eq(
+        arr.rows(),
+        [&[5, 6, 3], &[8, 9, 6], &[7, 8, 9]] as [&[_]; 3],
+    );
+
+    let mut arr = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], [0, 0]..=[2, 2]);
+    arr.copy_within([-1, 0]..=[1, 0], [1, 2]);
+    iter_eq(
+        arr.rows(),
+        [&[1, 2, 3], &[4, 5, 6], &[7, 8, 1]] as [&[_]; 3],
+    );
+
+    let mut arr = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], [0, 0]..=[2, 2]);
+    arr.move_within([0, 0]..=[1, 1], [1, 0]);
+    iter_eq(
+        arr.rows(),
+        [&[0, 1, 2], &[0, 4, 5], &[7, 8, 9]] as [&[_]; 3],
+    );
+
+    let mut arr = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6], [-1, -1]..=[0, 1]);
+    arr.scroll([1, -1]);
+    iter_eq(arr.rows(), [&[0, 3], &[0, 5], &[0, 0]] as [&[_]; 3]);
+    assert_eq!(arr.boundary(), Boundary::min_max([-1, -1], [0, 1]));
+
+    arr.scroll([0, 3]);
+    iter_eq(arr.rows(), [&[0, 0], &[0, 0], &[0, 0]] as [&[_]; 3]);
+
+    let mut arr = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], [0, 0]..=[2, 2]);
+    arr.move_within([1, 0]..=[2, 0], [3, 0]);
+    iter_eq(
+        arr.rows(),
+        [&[1, 0, 0], &[4, 5, 6], &[7, 8, 9]] as [&[_]; 3],
+    );
+    arr.move_within([0, 1]..=[2, 1], [2, 2]);
+    iter_eq(
+        arr.rows(),
+        [&[1, 0, 0], &[0, 0, 0], &[7, 8, 4]] as [&[_]; 3],
+    );
+
+    arr.copy_within(.., [i32::MAX, i32::MAX]);
+    arr.copy_within([0, 0]..=[0, 0], [i32::MIN, 0]);
+    arr.move_within([2, 2]..=[2, 2], [i32::MIN, i32::MIN]);
+    iter_eq(
+        arr.rows(),
+        [&[1, 0, 0], &[0, 0, 0], &[7, 8, 0]] as [&[_]; 3],
+    );
+    arr.scroll([i32::MIN, 0]);
+    assert!(arr.values().all(|v| *v == 0));
+
+    // Arrays ending at `i32::MAX`.
+    const MAX: i32 = i32::MAX;
+    let edge = Boundary::min_dim([MAX - 1, MAX - 1], [2, 2]);
+    let mut arr = Array2d::init(edge, |v: [i32; 2]| v[0]);
+    arr.scroll([-1, 0]);
+    iter_eq(arr.values(), [&MAX, &0, &MAX, &0]);
+    let mut arr = Array2d::init(edge, |v: [i32; 2]| v[1]);
+    arr.move_within([MAX - 1, MAX - 1]..=[MAX, MAX - 1], [MAX - 1, MAX]);
+    iter_eq(arr.values(), [&0, &0, &(MAX - 1), &(MAX - 1)]);
+    arr.copy_within([MAX, MAX]..=[MAX, MAX], [MAX - 1, MAX - 1]);
+    iter_eq(arr.values(), [&(MAX - 1), &0, &(MAX - 1), &(MAX - 1)]);
+    arr.scroll([1, 1]);
+    iter_eq(arr.values(), [&0, &0, &0, &(MAX - 1)]);
+}
+
+#[test]
+pub fn index() {
+    let mut arr = Array2d::init([-1, -1]..=[1, 1], |v: IVec2| v.x * 3 + v.y);
+    assert_eq!(arr[[-1, -1]], -4);
+    assert_eq!(arr[IVec2::new(1, 0)], 3);
+    arr[[0, 1]] = 10;
+    assert_eq!(arr[[0, 1]], 10);
+    unsafe {
+        assert_eq!(*arr.get_unchecked([1, 1]), 4);
+        *arr.get_unchecked_mut([1, 1]) = 5;
+    }
+    assert_eq!(arr[[1, 1]], 5);
+
+    let result = std::panic::catch_unwind(|| arr[[2, 0]]);
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn arithmetic() {
+    let a = Array2d::from_vec(vec![1, 2, 3, 4, 5, 6], [0, 0]..=[2, 1]);
+    let b = Array2d::from_vec(vec![6, 5, 4, 3, 2, 1], [4, 4]..=[6, 5]);
+
+    let c = &a + &b;
+    assert_eq!(c.boundary(), a.boundary());
+    iter_eq(c.rows(), [&[7, 7, 7], &[7, 7, 7]] as [&[_]; 2]);
+
+    let c = &a * 2;
+    iter_eq(c.rows(), [&[2, 4, 6], &[8, 10, 12]] as [&[_]; 2]);
+
+    let c = -&a;
+    iter_eq(c.rows(), [&[-1, -2, -3], &[-4, -5, -6]] as [&[_]; 2]);
+
+    let mut c = a.clone() - &b;
+    iter_eq(c.rows(), [&[-5, -3, -1], &[1, 3, 5]] as [&[_]; 2]);
+
+    c /= 2;
+    iter_eq(c.rows(), [&[-2, -1, 0], &[0, 1, 2]] as [&[_]; 2]);
+
+    c += &a;
+    iter_eq(c.rows(), [&[-1, 1, 3], &[4, 6, 8]] as [&[_]; 2]);
+
+    let mut s = c.slice_mut([1, 0]..=[1, 1]);
+    s -= 1;
+    iter_eq(c.rows(), [&[-1, 0, 3], &[4, 5, 8]] as [&[_]; 2]);
+
+    let mut canvas = Array2d::<i32>::new([0, 0]..=[2, 1]);
+    canvas.add_at(&a, [1, 1]);
+    iter_eq(canvas.rows(), [&[0, 0, 0], &[0, 1, 2]] as [&[_]; 2]);
+
+    // Owned operators only require the operator, like borrowed ones.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Meters(i32);
+
+    impl std::ops::Add for Meters {
+        type Output = Meters;
+
+        fn add(self, rhs: Meters) -> Meters {
+            Meters(self.0 + rhs.0)
+        }
+    }
+
+    // Scalar operands opt in with `Scalar`.
+    impl traits::Scalar for Meters {}
+
+    let m = Array2d::from_vec(vec![Meters(1), Meters(2)], [2, 1]);
+    let m = m.clone() + &m;
+    iter_eq(m.values().copied(), [Meters(2), Meters(4)]);
+    let m = m + Meters(1);
+    iter_eq(m.values().copied(), [Meters(3), Meters(5)]);
+}
+
+#[test]
+pub fn reductions() {
+    let a = Array2d::from_vec(vec![3, 1, 4, 1, 5, 9, 2, 6, 5], [-1, -1]..=[1, 1]);
+
+    assert_eq!(a.sum::<i32>(), 36);
+    assert_eq!(a.min(), Some(&1));
+    assert_eq!(a.max(), Some(&9));
+    assert_eq!(a.argmin::<[i32; 2]>(), Some([0, -1]));
+    assert_eq!(a.argmax::<[i32; 2]>(), Some([1, 0]));
+    assert_eq!(a.mean(), Some(4.0));
+    let wide = a.mapped(|x| *x as i64 * 1_000_000_000_000);
+    assert_eq!(wide.mean_by(|x| *x as f64), Some(4e12));
+    assert_eq!(a.count(|x| *x > 4), 4);
+    assert_eq!(a.histogram(3, |x| (*x / 3) as usize), vec![3, 4, 1]);
+    assert_eq!(a.counts().get(&5), Some(&2));
+
+    assert_eq!(a.row_sums::<i32>(), vec![8, 15, 13]);
+    assert_eq!(a.column_sums::<i32>(), vec![6, 12, 18]);
+    assert_eq!(a.fold_rows(0, |acc, x| acc.max(*x)), vec![4, 9, 6]);
+    assert_eq!(a.fold_columns(1, |acc, x| acc * x), vec![6, 30, 180]);
+
+    let slice = a.slice([0, 0]..);
+    assert_eq!(slice.sum::<i32>(), 25);
+    assert_eq!(slice.argmax::<[i32; 2]>(), Some([1, 0]));
+    assert_eq!(slice.row_sums::<i32>(), vec![14, 11]);
+
+    let empty = Array2d::<f32>::default();
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.mean(), None);
+}
+
+#[test]
+pub fn zip_n() {
+    let height = Array2d::from_vec(vec![1, 2, 3, 4], [0, 0]..=[1, 1]);
+    let moisture = Array2d::from_vec(vec![5, 6, 7, 8], [2, 2]..=[3, 3]);
+    let mut biome = Array2d::<i32>::new([-1, -1]..=[0, 0]);
+
+    let mut zip = height.zip(&moisture).and(&mut biome);
+    assert!(zip.is_valid());
+    zip.for_each_mut(|h, m, b| *b = h * 10 + m);
+    iter_eq(biome.rows(), [&[15, 26], &[37, 48]] as [&[_]; 2]);
+
+    let temperature = Array2d::from_vec(vec![1, 0, 1, 0], [0, 0]..=[1, 1]);
+    let v = Zip4(&height, &moisture, &biome, &temperature).map(|h, m, b, t| h + m + b * t);
+    assert_eq!(v.boundary(), height.boundary());
+    iter_eq(v.rows(), [&[21, 8], &[47, 12]] as [&[_]; 2]);
+
+    let small = Array2d::<i32>::new([0, 0]..=[0, 1]);
+    assert!(!Zip3(&height, &moisture, &small).is_valid());
+    assert!(!Zip3(&height, &moisture, &small).for_each(|_, _, _| ()));
+    assert!(!Zip3(&height, &moisture, &small).for_each_indexed(|_: [i32; 2], _, _, _, _, _| ()));
+
+    let mut points = Vec::new();
+    Zip3(&height, &moisture, &biome)
+        .for_each_indexed(|p0: [i32; 2], h, p1, m, p2, _| points.push((p0, p1, p2, h + m)));
+    assert_eq!(points[3], ([1, 1], [3, 3], [0, 0], 12));
+
+    Zip4(&height, &moisture, &temperature, &mut biome)
+        .for_each_indexed_mut(|_: IVec2, h, _, m, _, t, p, b| *b = h + m * t + p.x);
+    iter_eq(biome.rows(), [&[5, 2], &[9, 4]] as [&[_]; 2]);
+}
+
+#[test]
+pub fn zip_aligned() {
+    let a = Array2d::init([0, 0]..=[2, 2], |v: IVec2| v.x + v.y * 10);
+    let mut b = Array2d::<i32>::new([1, 2]..=[4, 3]);
+
+    let mut zip = b.zip_aligned_mut(&a);
+    assert!(zip.is_valid());
+    zip.for_each_mut(|b, a| *b = *a);
+    iter_eq(b.rows(), [&[21, 22, 0, 0], &[0, 0, 0, 0]] as [&[_]; 2]);
+
+    let mut points = Vec::new();
+    a.zip_aligned(&b)
+        .for_each_indexed(|p0: IVec2, _, p1: IVec2, v| points.push((p0, p1, *v)));
+    assert_eq!(
+        points,
+        vec![
+            (IVec2::new(1, 2), IVec2::new(1, 2), 21),
+            (IVec2::new(2, 2), IVec2::new(2, 2), 22),
+        ]
+    );
+
+    // Positions are reported as `(x, y)` in row major order on non square intersections.
+    let d = Array2d::init([-1, 0]..=[3, 1], |v: IVec2| v.x + v.y * 10);
+    let mut points = Vec::new();
+    a.zip_aligned(&d)
+        .for_each_indexed(|p0: IVec2, _, _: IVec2, v| points.push((p0, *v)));
+    assert_eq!(
+        points,
+        vec![
+            (IVec2::new(0, 0), 0),
+            (IVec2::new(1, 0), 1),
+            (IVec2::new(2, 0), 2),
+            (IVec2::new(0, 1), 10),
+            (IVec2::new(1, 1), 11),
+            (IVec2::new(2, 1), 12),
+        ]
+    );
+    let mut d = d;
+    d.zip_aligned_mut(&a)
+        .for_each_indexed_mut(|p0: IVec2, d, _: IVec2, a| *d = p0.x + p0.y * 10 - *a);
+    iter_eq(
+        d.rows(),
+        [&[-1, 0, 0, 0, 3], &[9, 0, 0, 0, 13]] as [&[_]; 2],
+    );
+
+    let c = Array2d::<i32>::new([3, 3]..=[4, 4]);
+    assert!(a.zip_aligned(&c).for_each(|_, _| panic!()));
+
+    let a = Array2d::from_vec(vec![1, 2], [0, 0]..=[1, 0]);
+    let b = Array2d::from_vec(vec![3, 4], [1, 1]..=[2, 1]);
+    iter_eq(
+        a.zip_union::<_, [i32; 2]>(&b),
+        [
+            ([0, 0], Some(&1), None),
+            ([1, 0], Some(&2), None),
+            ([2, 0], None, None),
+            ([0, 1], None, None),
+            ([1, 1], None, Some(&3)),
+            ([2, 1], None, Some(&4)),
+        ],
+    );
+}
+
+#[test]
+pub fn entry() {
+    let mut a = Array2d::<u32>::default();
+
+    *a.entry([1, 1]).or_insert(3) += 1;
+    assert_eq!(a.boundary(), Boundary::from_point([1, 1]));
+    assert_eq!(a[[1, 1]], 4);
+
+    *a.entry([1, 1]).or_insert(7) += 1;
+    assert_eq!(a[[1, 1]], 5);
+
+    a.entry([0, 2]).and_modify(|x| *x = 100).or_default();
+    assert_eq!(a.boundary(), Boundary::min_max([0, 1], [1, 2]));
+    assert_eq!(a[[0, 2]], 0);
+    assert_eq!(a[[1, 1]], 5);
+
+    a.entry([0, 2]).and_modify(|x| *x = 100).or_default();
+    assert_eq!(a[[0, 2]], 100);
+
+    assert_eq!(*a.entry([3, 1]).or_insert_with(|| 9), 9);
+    assert_eq!(a.boundary(), Boundary::min_max([0, 1], [3, 2]));
+    assert_eq!(a.entry([3, 1]).position::<[i32; 2]>(), [3, 1]);
+}
+
+#[test]
+pub fn capacity() {
+    let mut a = Array2d::<i32>::default();
+    let mut reallocations = 0;
+    let mut capacity = a.capacity();
+    for i in 0..100 {
+        a.insert([-i, i / 2], i);
+        if a.capacity() != capacity {
+            reallocations += 1;
+            capacity = a.capacity();
+        }
+        assert!(capacity.intersection(a.boundary()) == Some(a.boundary()));
+    }
+    assert!(reallocations < 20);
+    assert_eq!(a.boundary(), Boundary::min_max([-99, 0], [0, 49]));
+    for i in 0..100 {
+        assert_eq!(a[[-i, i / 2]], i);
+    }
+    assert_eq!(a.sum::<i32>(), (0..100).sum());
+
+    a.shrink_to_fit();
+    assert_eq!(a.capacity(), a.boundary());
+    assert_eq!(a.pitch(), 100);
+    assert_eq!(a.sum::<i32>(), (0..100).sum());
+
+    let mut b = Array2d::from_vec(vec![1, 2, 3, 4], [0, 0]..=[1, 1]);
+    b.reserve([-2, -2]..=[3, 3]);
+    assert_eq!(b.capacity(), Boundary::min_max([-2, -2], [3, 3]));
+    iter_eq(b.rows(), [&[1, 2], &[3, 4]] as [&[_]; 2]);
+
+    b.resize_containing(Boundary::min_max([-1, 0], [1, 2]));
+    assert_eq!(b.capacity(), Boundary::min_max([-2, -2], [3, 3]));
+    iter_eq(b.rows(), [&[0, 1, 2], &[0, 3, 4], &[0, 0, 0]] as [&[_]; 3]);
+
+    b.resize([0, 1]..=[1, 2]);
+    iter_eq(b.rows(), [&[3, 4], &[0, 0]] as [&[_]; 2]);
+    b.insert([2, 1], 5);
+    iter_eq(b.rows(), [&[3, 4, 5], &[0, 0, 0]] as [&[_]; 2]);
+
+    // Growing moves the array away from the start of its buffer, clearing must reset it.
+    let mut c = Array2d::<i32>::new([2, 2]);
+    c.insert([-1, -1], 1);
+    c.insert([-3, -3], 2);
+    c.clear();
+    assert!(c.is_empty());
+    assert_eq!(c.rows().count(), 0);
+    c.insert([1, 1], 3);
+    iter_eq(c.rows(), [&[3]] as [&[_]; 1]);
+
+    // Reserving on an empty array keeps its boundary, the capacity includes its `min`.
+    let mut d = Array2d::<i32>::default();
+    d.reserve([5, 5]..=[8, 8]);
+    assert_eq!(d.boundary(), Boundary::EMPTY);
+    assert_eq!(d, Array2d::default());
+    assert_eq!(d.capacity(), Boundary::min_max([0, 0], [8, 8]));
+    d.insert([6, 6], 1);
+    assert_eq!(d.capacity(), Boundary::min_max([0, 0], [8, 8]));
+    let empty = Boundary::min_dim([5, 5], [0, 0]);
+    let mut e = Array2d::<i32>::new(empty);
+    e.reserve([5, 5]..=[8, 8]);
+    assert_eq!(e.boundary(), empty);
+    assert_eq!(e.capacity(), Boundary::min_max([5, 5], [8, 8]));
+    assert_ne!(e, Array2d::default());
+    e.clear();
+    assert_eq!(e, Array2d::default());
+
+    // `extend` knows the final boundary and does not reserve extra capacity.
+    let mut f = Array2d::from_vec(vec![1, 2, 3, 4], [2, 0]..=[3, 1]);
+    f.extend([([0, 2], 1), ([1, 3], 4)]);
+    assert_eq!(f.capacity(), f.boundary());
+
+    // Resizing in place keeps the buffer, the capacity follows the narrower pitch.
+    let mut g = Array2d::<i32>::default();
+    for i in 0..20 {
+        g.insert([i, i], i);
+    }
+    let len = g.capacity().len();
+    g.resize([0, 0]..=[0, 0]);
+    assert_eq!(g.capacity(), Boundary::min_dim([0, 0], [1, len as i32]));
+    g.shrink_to_fit();
+    assert_eq!(g.capacity(), g.boundary());
+}
+
+#[test]
+pub fn map_padded() {
+    // Arrays grown with `insert` have a pitch larger than their width.
+    let mut a = Array2d::from_vec(vec![1, 2, 3, 4], [0, 0]..=[1, 1]);
+    a.insert([2, 1], 5);
+    assert!(a.pitch() > a.width());
+    let rows = [&[1, 2, 0], &[3, 4, 5]] as [&[_]; 2];
+    iter_eq(a.copied().rows(), rows);
+    iter_eq(a.cloned().rows(), rows);
+    iter_eq(a.mapped(|x| *x).rows(), rows);
+    assert_eq!(a.copied().pitch(), 3);
+
+    let b = Array2d::init([0, 0]..=[3, 3], |v: IVec2| v.x + v.y * 10);
+    let slice = b.slice([1, 1]..=[2, 3]);
+    let rows = [&[11, 12], &[21, 22], &[31, 32]] as [&[_]; 3];
+    iter_eq(slice.copied().rows(), rows);
+    iter_eq(slice.cloned().rows(), rows);
+    iter_eq(slice.mapped(|x| *x).rows(), rows);
+}
+
+#[test]
+pub fn resize_without_default() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct NoDefault(i32);
+
+    let mut arr = Array2d::init([0, 0]..=[1, 1], |v: IVec2| NoDefault(v.x + v.y * 2));
+    arr.resize_with([-1, 1]..=[1, 2], |v: IVec2| NoDefault(v.x * 10));
+    iter_eq(arr.values().map(|x| x.0), [-10, 2, 3, -10, 0, 10]);
+
+    arr.resize_filled([0, 2]..=[1, 3], NoDefault(7));
+    iter_eq(arr.values().map(|x| x.0), [0, 10, 7, 7]);
+
+    let mut arr = Array2d::from_vec_pitch(vec![1, 2, 0, 3, 4, 0], [0, 0]..[2, 2], 3);
+    arr.resize_anchored([4, 4], Anchor::Center);
+    assert_eq!(arr.boundary(), Boundary::min_max([-1, -1], [2, 2]));
+    iter_eq(
+        arr.rows(),
+        [&[0, 0, 0, 0], &[0, 1, 2, 0], &[0, 3, 4, 0], &[0, 0, 0, 0]] as [&[_]; 4],
+    );
+
+    arr.resize_anchored_with([3, 2], Anchor::BottomRight, |_: IVec2| 9);
+    assert_eq!(arr.boundary(), Boundary::min_max([0, 1], [2, 2]));
+    iter_eq(arr.rows(), [&[3, 4, 0], &[0, 0, 0]] as [&[_]; 2]);
+
+    let b = Boundary::min_max([0, 0], [3, 3]);
+    assert_eq!(
+        b.anchored([2, 2], Anchor::Center),
+        Boundary::min_max([1, 1], [2, 2])
+    );
+    assert_eq!(
+        b.anchored([5, 1], Anchor::Top),
+        Boundary::min_dim([-1, 0], [5, 1])
+    );
+}
+
+#[test]
+pub fn trim() {
+    let mut a = Array2d::init([-3, -3]..=[3, 3], |v: IVec2| {
+        if v.x >= -1 && v.x <= 0 && v.y >= 1 {
+            v.y
+        } else {
+            0
+        }
+    });
+    assert_eq!(
+        a.content_boundary(|x| *x == 0),
+        Boundary::min_max([-1, 1], [0, 3])
+    );
+    a.trim(|x| *x == 0);
+    assert_eq!(a.boundary(), Boundary::min_max([-1, 1], [0, 3]));
+    iter_eq(a.rows(), [&[1, 1], &[2, 2], &[3, 3]] as [&[_]; 3]);
+
+    let mut b = Array2d::init([0, 0]..=[4, 4], |v: IVec2| v == IVec2::new(2, 3));
+    assert_eq!(b.points_boundary(), Boundary::from_point([2, 3]));
+    b.trim_points();
+    iter_eq(b.rows(), [&[true]] as [&[_]; 1]);
+
+    let mut c = Array2d::init([0, 0]..=[4, 4], |v: IVec2| (v.x == 4).then_some(v.y));
+    assert_eq!(c.some_boundary(), Boundary::min_max([4, 0], [4, 4]));
+    c.trim_some();
+    assert_eq!(c.len(), 5);
+
+    let mut d = Array2d::<Option<i32>>::new([0, 0]..=[4, 4]);
+    d.trim_some();
+    assert!(d.is_empty());
+
+    let mut e = Array2d::from_vec(vec![0, 1, 0, 0], [2, 2]);
+    e.insert([3, 1], 2);
+    e.trim(|x| *x == 0);
+    assert_eq!(e.boundary(), Boundary::min_max([1, 0], [3, 1]));
+    iter_eq(e.rows(), [&[1, 0, 0], &[0, 0, 2]] as [&[_]; 2]);
+
+    let mut f = GenericArray2d::<Chunked<i32>>::init([3, 3], |[x, y]: [i32; 2]| x * y);
+    f.trim(|x| *x == 0);
+    assert_eq!(f.boundary(), Boundary::min_max([1, 1], [2, 2]));
+    iter_eq(f.values().copied(), [1, 2, 2, 4]);
+}
+
+#[test]
+pub fn scrolling() {
+    let mut calls = 0;
+    let mut window = ScrollingArray2d::init([0, 0]..[3, 3], |v: IVec2| v.x + v.y * 10);
+    assert_eq!(window.get([2, 1]), Some(&12));
+
+    window.recenter_with([1, -1]..[4, 2], |v: IVec2| {
+        calls += 1;
+        v.x + v.y * 10 + 100
+    });
+    assert_eq!(calls, 5);
+    assert_eq!(window.get([0, 0]), None);
+    iter_eq(
+        window.rows().map(|(a, b)| [a, b].concat()),
+        [vec![91, 92, 93], vec![1, 2, 103], vec![11, 12, 113]],
+    );
+    iter_eq(
+        window.iter::<[i32; 2]>().map(|(p, v)| (p, *v)).take(4),
+        [([1, -1], 91), ([2, -1], 92), ([3, -1], 93), ([1, 0], 1)],
+    );
+
+    for (_, v) in window.iter_mut::<IVec2>() {
+        *v += 1;
+    }
+    window.set([3, 1], 0);
+
+    let array: Array2d<i32> = window.clone().into();
+    assert_eq!(array.boundary(), Boundary::min_max([1, -1], [3, 1]));
+    iter_eq(
+        array.rows(),
+        [&[92, 93, 94], &[2, 3, 104], &[12, 13, 0]] as [&[_]; 3],
+    );
+    assert_eq!(array, window.cloned());
+    assert_eq!(ScrollingArray2d::from(array), window);
+
+    window.scroll([10, 0]);
+    assert!(window.values().all(|x| *x == 0));
+}
+
+#[test]
+pub fn scrolling_extreme() {
+    let boundary = Boundary::min_dim([i32::MIN, i32::MAX - 2], [3, 2]);
+    let window = ScrollingArray2d::init(boundary, |[x, y]: [i32; 2]| {
+        (x.abs_diff(i32::MIN) + y.abs_diff(i32::MAX - 2) * 3) as u8
+    });
+    iter_eq(window.values().copied(), 0..6);
+    assert_eq!(window.get([i32::MIN + 2, i32::MAX - 1]), Some(&5));
+    let array = window.cloned();
+    assert_eq!(ScrollingArray2d::from(array), window);
+}
+
+#[test]
+pub fn layered() {
+    let mut map =
+        Layered::<(Array2d<u8>, Array2d<bool>, Array2d<Option<i32>>)>::new([0, 0]..[2, 2]);
+    assert!(map.set([1, 1], (4, true, Some(2))));
+    assert!(!map.set([2, 1], (4, true, Some(2))));
+
+    map.insert([-1, 2], (1, false, Some(3)));
+    assert_eq!(map.boundary(), Boundary::min_max([-1, 0], [1, 2]));
+    assert_eq!(map.layers().0.boundary(), map.layers().2.boundary());
+    assert_eq!(map.get([1, 1]), Some((&4, &true, &Some(2))));
+    assert_eq!(map.get([-1, 2]), Some((&1, &false, &Some(3))));
+    assert_eq!(map.get([-1, 0]), Some((&0, &false, &None)));
+    assert_eq!(map.get([2, 0]), None);
+
+    if let Some((ground, _, object)) = map.get_mut([0, 0]) {
+        *ground = 7;
+        *object = Some(9);
+    }
+
+    map.displace([1, 1]);
+    assert_eq!(map.get([1, 1]), Some((&7, &false, &Some(9))));
+
+    let (ground, collision, _) = map.slice([1, 2]..);
+    iter_eq(ground.rows(), [&[0, 4], &[0, 0]] as [&[_]; 2]);
+    iter_eq(
+        collision.rows(),
+        [&[false, true], &[false, false]] as [&[_]; 2],
+    );
+
+    map.resize([0, 0]..[2, 2]);
+    assert_eq!(map.layers().1.boundary(), Boundary::min_max([0, 0], [1, 1]));
+    assert_eq!(map.iter::<[i32; 2]>().count(), 4);
+
+    let (a, b) = (Array2d::<i32>::new([2, 2]), Array2d::<i32>::new([2, 2]));
+    let layers = Layered::from_layers((a, b));
+    assert_eq!(layers.get([1, 1]), Some((&0, &0)));
+}
+
+#[test]
+pub fn array3d() {
+    let mut array = Array3d::<i32>::init([3, 2, 2], |[x, y, z]: [i32; 3]| x + y * 10 + z * 100);
+    assert_eq!(array.len(), 12);
+    assert_eq!(array.get([2, 1, 1]), Some(&112));
+    assert_eq!(array.get([3, 1, 1]), None);
+    iter_eq(
+        array.values().copied(),
+        [0, 1, 2, 10, 11, 12, 100, 101, 102, 110, 111, 112],
+    );
+
+    let layer = array.layer(1).unwrap();
+    assert_eq!(layer.boundary(), Boundary::min_max([0, 0], [2, 1]));
+    iter_eq(
+        layer.rows(),
+        [&[100, 101, 102], &[110, 111, 112]] as [&[_]; 2],
+    );
+    assert!(array.layer(2).is_none());
+    assert_eq!(
+        Array3d::try_from_vec(vec![0; 11], [3, 2, 2]),
+        Err(Array2dError::NotEnoughItems {
+            needed: 12,
+            got: 11
+        })
+    );
+    assert_eq!(
+        Array3d::<i32>::try_from_vec(array.values().copied().collect(), [3, 2, 2]).unwrap(),
+        array
+    );
+
+    let xz = array.layer_xz(1).unwrap();
+    iter_eq(xz.rows(), [&[10, 11, 12], &[110, 111, 112]] as [&[_]; 2]);
+
+    array.layer_xz_mut(0).unwrap().fill(-1);
+    iter_eq(
+        array.layer(0).unwrap().rows(),
+        [&[-1, -1, -1], &[10, 11, 12]] as [&[_]; 2],
+    );
+
+    let slice = array.slice(Boundary3::min_max([1, 1, 0], [2, 1, 1]));
+    iter_eq(slice.values().copied(), [11, 12, 111, 112]);
+    iter_eq(slice.layer(1).unwrap().values().copied(), [111, 112]);
+    assert_eq!(slice.cloned(), slice);
+
+    array
+        .slice_mut(Boundary3::min_max([0, 0, 1], [0, 1, 1]))
+        .fill(7);
+    iter_eq(
+        array.layer(1).unwrap().rows(),
+        [&[7, -1, -1], &[7, 111, 112]] as [&[_]; 2],
+    );
+
+    array.insert([-1, 0, 0], 5);
+    assert_eq!(array.boundary(), Boundary3::min_max([-1, 0, 0], [2, 1, 1]));
+    assert_eq!(array.get([-1, 0, 0]), Some(&5));
+    assert_eq!(array.get([-1, 1, 1]), Some(&0));
+    assert_eq!(array.get([2, 1, 1]), Some(&112));
+
+    let brush = Array3d::new_filled([2, 1, 1], 9);
+    array.paint(&brush, [1, 1, 1], |a, b| *a = *b);
+    iter_eq(
+        array.layer(1).unwrap().rows(),
+        [&[0, 7, -1, -1], &[0, 7, 9, 9]] as [&[_]; 2],
+    );
+}
+
+#[test]
+pub fn fixed() {
+    let kernel = FixedArray2d::from_rows_at([[1, 2, 1], [2, 4, 2], [1, 2, 1]], [-1, -1]);
+    assert_eq!(kernel.boundary(), Boundary::min_max([-1, -1], [1, 1]));
+    assert_eq!(kernel.get([0, 0]), Some(&4));
+    assert_eq!(kernel.get([1, -1]), Some(&1));
+    assert_eq!(kernel.get([2, 0]), None);
+    assert_eq!(kernel.values().sum::<i32>(), 16);
+
+    let mut array = Array2d::<i32>::new([4, 4]);
+    array.paint(&kernel, [1, 1], |a, b| *a += b);
+    array.paint(&kernel, [2, 2], |a, b| *a += b);
+    iter_eq(
+        array.rows(),
+        [&[1, 2, 1, 0], &[2, 5, 4, 1], &[1, 4, 5, 2], &[0, 1, 2, 1]] as [&[_]; 4],
+    );
+
+    let mut stencil = FixedArray2d::<bool, 2, 2>::default();
+    stencil.set([1, 0], true);
+    let ones = FixedArray2d::<_, 2, 2>::from_fn(|[x, y]: [i32; 2]| x + y * 2);
+    iter_eq(
+        stencil.zip(&ones).map(|a, b| *a as i32 * b).rows(),
+        [&[0, 1], &[0, 0]] as [&[_]; 2],
+    );
+    assert_eq!(stencil.into_rows(), [[false, true], [false, false]]);
+}
+
+#[test]
+pub fn column_major() {
+    let mut array =
+        ColumnMajorArray2d::from_vec(vec![1, 2, 3, 4, 5, 6], Boundary::min_dim([-1, 0], [2, 3]));
+    assert_eq!(array.width(), 2);
+    assert_eq!(array.height(), 3);
+    assert_eq!(array.get([-1, 0]), Some(&1));
+    assert_eq!(array.get([-1, 2]), Some(&3));
+    assert_eq!(array.get([0, 0]), Some(&4));
+    assert_eq!(array.get([1, 0]), None);
+    iter_eq(array.columns(), [&[1, 2, 3], &[4, 5, 6]] as [&[_]; 2]);
+    assert_eq!(
+        ColumnMajorArray2d::try_from_vec(vec![1, 2, 3, 4, 5], [2, 3]).unwrap_err(),
+        Array2dError::NotEnoughItems { needed: 6, got: 5 }
+    );
+    iter_eq(
+        array.rows().map(|r| r.copied().collect::<Vec<_>>()),
+        [vec![1, 4], vec![2, 5], vec![3, 6]],
+    );
+    iter_eq(
+        array.iter::<[i32; 2]>().map(|(p, _)| p),
+        [[-1, 0], [-1, 1], [-1, 2], [0, 0], [0, 1], [0, 2]],
+    );
+
+    let slice = array.slice(Boundary::min_max([-1, 1], [0, 1]));
+    assert_eq!(slice.boundary(), Boundary::min_max([-1, 1], [0, 1]));
+    iter_eq(slice.columns(), [&[2], &[5]] as [&[_]; 2]);
+
+    let brush = ColumnMajorArray2d::from_vec(vec![10, 20], [2, 1]);
+    array.paint(&brush, [-1, 2], |a, b| *a += b);
+    assert_eq!(array.get([-1, 2]), Some(&13));
+    assert_eq!(array.get([0, 2]), Some(&26));
+
+    let row_major = array.to_row_major();
+    iter_eq(row_major.rows(), [&[1, 4], &[2, 5], &[13, 26]] as [&[_]; 3]);
+    iter_eq(
+        row_major.columns().map(|c| c.copied().collect::<Vec<_>>()),
+        [vec![1, 2, 13], vec![4, 5, 26]],
+    );
+    let back = ColumnMajorArray2d::from(row_major);
+    iter_eq(back.values(), array.values());
+    let owned: Array2d<_> = back.into();
+    assert_eq!(owned.boundary(), Boundary::min_dim([-1, 0], [2, 3]));
+    iter_eq(owned.values().copied(), [1, 4, 2, 5, 13, 26]);
+}
+
+#[test]
+pub fn tiled() {
+    let boundary = Boundary::min_dim([-3, 2], [13, 9]);
+    let mut tiled = TiledArray2d::init(boundary, |[x, y]: [i32; 2]| x * 100 + y);
+    assert_eq!(tiled.len(), 117);
+    assert_eq!(tiled.get([-3, 2]), Some(&-298));
+    assert_eq!(tiled.get([9, 10]), Some(&910));
+    assert_eq!(tiled.get([10, 10]), None);
+    assert_eq!(tiled.get([9, 11]), None);
+    assert_eq!(tiled.iter::<[i32; 2]>().count(), 117);
+    assert!(
+        tiled
+            .iter::<[i32; 2]>()
+            .all(|([x, y], v)| *v == x * 100 + y)
+    );
+
+    assert!(tiled.set([4, 9], 0));
+    assert!(!tiled.set([4, 11], 0));
+    *tiled.get_mut([5, 9]).unwrap() = -1;
+
+    let array: Array2d<i32> = tiled.clone().into();
+    assert_eq!(array.boundary(), boundary);
+    assert_eq!(array.get([4, 9]), Some(&0));
+    assert_eq!(array.get([5, 9]), Some(&-1));
+    assert_eq!(array.get([6, 9]), Some(&609));
+    iter_eq(array.values(), tiled.cloned().values());
+
+    let back = TiledArray2d::from(array);
+    assert_eq!(back, tiled);
+
+    let dense = tiled.cloned();
+    assert_eq!(tiled.as_array(), dense);
+    assert_eq!(tiled.as_array().sum::<i32>(), dense.sum::<i32>());
+    tiled
+        .as_array()
+        .zip(&dense)
+        .for_each(|a, b| assert_eq!(a, b));
+    let brush = Array2d::new_filled(Boundary::min_dim([0, 0], [9, 3]), 1);
+    tiled.as_array_mut().paint(&brush, [1, 5], |a, b| *a += *b);
+    assert_eq!(tiled.get([1, 5]), Some(&106));
+    assert_eq!(tiled.get([9, 7]), Some(&908));
+    assert_eq!(tiled.get([9, 8]), Some(&908));
+    assert_eq!(tiled.get([0, 5]), Some(&5));
+    assert!(tiled.as_array_mut().set([-3, 2], 7));
+    assert_eq!(tiled.get([-3, 2]), Some(&7));
+
+    let mut tiled = TiledArray2d::init(Boundary::min_dim([1, 1], [10, 2]), |[x, y]: [i32; 2]| {
+        x + y * 10
+    });
+    iter_eq(tiled.values().copied(), (11..21).chain(21..31));
+    iter_eq(tiled.values_mut().map(|v| *v), (11..21).chain(21..31));
+    iter_eq(
+        tiled.iter_mut::<[i32; 2]>().map(|(p, _)| p),
+        Boundary::min_dim([1, 1], [10, 2]).iter::<[i32; 2]>(),
+    );
+    iter_eq(
+        tiled.iter_tiled::<[i32; 2]>().map(|(_, v)| *v).take(4),
+        [11, 12, 21, 22],
+    );
+    assert_eq!(tiled.iter_tiled_mut::<[i32; 2]>().count(), 20);
+    assert_eq!(
+        TiledArray2d::<i32>::default()
+            .iter_mut::<[i32; 2]>()
+            .count(),
+        0
+    );
+}
+
+#[test]
+pub fn tiled_near_max() {
+    let boundary = Boundary::min_dim([i32::MAX - 3, 0], [1, 1]);
+    let tiled = TiledArray2d::<u8>::new(boundary);
+    iter_eq(tiled.values().copied(), [0]);
+    let tiled = TiledArray2d::init(
+        Boundary::min_dim([i32::MAX - 9, i32::MAX - 2], [9, 2]),
+        |[x, y]: [i32; 2]| (x - (i32::MAX - 9)) + (y - (i32::MAX - 2)) * 9,
+    );
+    assert_eq!(tiled.get([i32::MAX - 1, i32::MAX - 1]), Some(&17));
+    assert_eq!(tiled.iter::<[i32; 2]>().count(), 18);
+}
+
+#[test]
+pub fn mask() {
+    let circle = |[x, y]: [i32; 2]| x * x + y * y <= 900;
+    let a = Mask2d::init(Boundary::min_max([-30, -30], [30, 30]), circle);
+    let b = Mask2d::init(
+        Boundary::min_max([-7, -40], [100, 10]),
+        |[x, y]: [i32; 2]| (x + y) % 3 == 0,
+    );
+    let array_a = a.to_array();
+    let array_b = b.to_array();
+    assert_eq!(a.count_ones(), array_a.values().filter(|v| **v).count());
+    assert_eq!(a.count_zeros(), a.len() - a.count_ones());
+    assert!(a.get([0, 30]));
+    assert!(!a.get([30, 30]));
+    assert!(!a.get([100, 100]));
+    iter_eq(
+        a.iter_points::<[i32; 2]>(),
+        array_a
+            .iter::<[i32; 2]>()
+            .filter(|(_, v)| **v)
+            .map(|(p, _)| p),
+    );
+    assert_eq!(a.as_array(), array_a);
+    assert_eq!(a.as_array().count(|v| *v), a.count_ones());
+    assert_eq!(a.as_array().argmax::<[i32; 2]>(), Some([0, -30]));
+    a.as_array().zip(&array_a).for_each(|x, y| assert_eq!(x, y));
+    iter_eq(
+        b.as_array().iter_points::<[i32; 2]>(),
+        b.iter_points::<[i32; 2]>(),
+    );
+    let mut c = b.clone();
+    assert!(c.as_array_mut().set([99, 9], false));
+    assert!(!c.as_array_mut().set([101, 10], true));
+    assert!(b.get([99, 9]) && !c.get([99, 9]));
+    assert_eq!(c.count_ones(), b.count_ones() - 1);
+
+    let and = &a & &b;
+    let or = &a | &b;
+    let xor = &a ^ &b;
+    assert_eq!(and.boundary(), Boundary::min_max([-7, -30], [30, 10]));
+    assert_eq!(or.boundary(), Boundary::min_max([-30, -40], [100, 30]));
+    assert_eq!(xor.boundary(), or.boundary());
+    for p in or.boundary().iter::<[i32; 2]>() {
+        let (x, y) = (a.get(p), b.get(p));
+        assert_eq!(and.get(p), x && y);
+        assert_eq!(or.get(p), x || y);
+        assert_eq!(xor.get(p), x != y);
+    }
+    assert_eq!(
+        or.count_ones(),
+        a.count_ones() + b.count_ones() - and.count_ones()
+    );
+
+    let not = !&a;
+    assert_eq!(not.count_ones(), a.count_zeros());
+    assert_eq!(!not, a);
+
+    let mask = Mask2d::from(&array_b);
+    assert_eq!(mask, b);
+    assert_eq!(Array2d::from(&mask), array_b);
+
+    let mut full = Mask2d::new_filled(Boundary::min_max([0, 0], [69, 2]));
+    assert_eq!(full.count_ones(), 210);
+    assert!(full.set([65, 1], false));
+    assert!(!full.set([70, 1], false));
+    assert_eq!(full.count_ones(), 209);
+    full.displace([-5, 0]);
+    assert!(!full.get([60, 1]));
+    assert_eq!(
+        full.resized(Boundary::min_max([-10, 0], [0, 0]))
+            .count_ones(),
+        6
+    );
+    assert_eq!(
+        Mask2d::from_points([[3, 4], [-1, 2]]).points_boundary(),
+        Boundary::min_max([-1, 2], [3, 4])
+    );
+}
+
+#[test]
+pub fn shared() {
+    let array = Array2d::init(Boundary::min_dim([-2, -2], [4, 4]), |[x, y]: [i32; 2]| {
+        x + y
+    });
+    let mut shared = SharedArray2d::from(array.clone());
+    assert!(!shared.is_shared());
+
+    let snapshot = shared.clone();
+    assert!(shared.is_shared());
+    assert!(shared.ptr_eq(&snapshot));
+    assert_eq!(shared[[1, 1]], 2);
+
+    shared[[1, 1]] = 100;
+    assert!(!shared.ptr_eq(&snapshot));
+    assert!(!shared.is_shared());
+    assert_eq!(shared[[1, 1]], 100);
+    assert_eq!(snapshot[[1, 1]], 2);
+    iter_eq(snapshot.values(), array.values());
+
+    let snapshot = shared.clone();
+    shared.resize(Boundary::min_dim([0, 0], [3, 3]));
+    assert_eq!(shared[[1, 1]], 100);
+    assert_eq!(shared[[2, 2]], 0);
+    assert_eq!(snapshot.boundary(), array.boundary());
+    assert_eq!(snapshot[[-2, -2]], -4);
+
+    let snapshot = shared.clone();
+    shared.slice_mut(Boundary::min_max([0, 0], [1, 1])).fill(7);
+    assert_eq!(shared[[1, 1]], 7);
+    assert_eq!(snapshot[[1, 1]], 100);
+
+    let array = snapshot.into_unshared();
+    assert_eq!(array[[1, 1]], 100);
+    assert_eq!(array.into_shared()[[0, 1]], 1);
+}
+
+#[test]
+pub fn rle() {
+    let boundary = Boundary::min_dim([-4, -2], [100, 3]);
+    let mut rle = RleArray2d::new(boundary);
+    assert_eq!(rle.run_count(), 3);
+    assert_eq!(rle.get([0, 0]), Some(&0));
+    assert_eq!(rle.get([96, 0]), None);
+
+    assert!(rle.set([10, 0], 1));
+    assert!(rle.set([11, 0], 1));
+    assert!(rle.set([-4, 0], 2));
+    assert!(rle.set([95, 0], 3));
+    assert!(!rle.set([96, 0], 3));
+    assert_eq!(rle.run_count(), 7);
+    assert_eq!(rle.get([11, 0]), Some(&1));
+    iter_eq(
+        rle.row_runs(0),
+        [
+            (-4..-3, &2),
+            (-3..10, &0),
+            (10..12, &1),
+            (12..95, &0),
+            (95..96, &3),
+        ],
+    );
+
+    // Merge runs back together.
+    assert!(rle.set([10, 0], 0));
+    assert!(rle.set([11, 0], 0));
+    assert_eq!(rle.run_count(), 5);
+    iter_eq(rle.row_runs(0), [(-4..-3, &2), (-3..95, &0), (95..96, &3)]);
+    assert_eq!(rle.row_runs(5).count(), 0);
+
+    let array = rle.cloned();
+    assert_eq!(array.boundary(), boundary);
+    assert_eq!(array[[-4, 0]], 2);
+    assert_eq!(array[[95, 0]], 3);
+    iter_eq(array.values(), rle.values());
+    iter_eq(
+        array.rows().map(|row| row.to_vec()),
+        rle.rows().map(|row| row.copied().collect()),
+    );
+    iter_eq(array.iter::<[i32; 2]>(), rle.iter());
+    assert_eq!(RleArray2d::from(&array), rle);
+    assert_eq!(RleArray2d::from(array), rle);
+
+    let rle = RleArray2d::init(boundary, |[x, y]: [i32; 2]| (x / 10 + y).max(0));
+    assert_eq!(rle.run_count(), 27);
+    assert_eq!(
+        Array2d::from(&rle),
+        Array2d::init(boundary, |[x, y]: [i32; 2]| (x / 10 + y).max(0))
+    );
+
+    let dense = Array2d::from(&rle);
+    assert_eq!(rle.as_array(), dense);
+    assert_eq!(rle.as_array().sum::<i32>(), dense.sum::<i32>());
+    assert_eq!(rle.as_array().get([95, 0]), Some(&9));
+    let doubled = &rle.as_array() + &dense;
+    assert_eq!(doubled, dense.mapped(|v| v * 2));
+    let mut rle = rle;
+    let runs = rle.run_count();
+    assert!(rle.as_array_mut().set([0, 0], 5));
+    assert!(!rle.as_array_mut().set([96, 0], 5));
+    assert_eq!(rle.get([0, 0]), Some(&5));
+    assert_eq!(rle.run_count(), runs + 2);
+}
+
+#[test]
+pub fn quadtree() {
+    let boundary = Boundary::min_dim([-10, -5], [100, 60]);
+    let mut tree = QuadTree2d::new(boundary);
+    assert_eq!(tree.leaf_count(), 1);
+    assert_eq!(tree.get([0, 0]), Some(&0));
+    assert_eq!(tree.get([90, 0]), None);
+
+    tree.fill(Boundary::min_dim([-10, -5], [32, 32]), 1);
+    assert_eq!(tree.leaf_count(), 5);
+    assert_eq!(tree.get([21, 26]), Some(&1));
+    assert_eq!(tree.get([22, 26]), Some(&0));
+
+    assert!(tree.set([50, 50], 2));
+    assert!(!tree.set([50, 55], 2));
+    assert_eq!(tree.get([50, 50]), Some(&2));
+
+    // Leaves cover the boundary exactly once.
+    assert_eq!(
+        tree.iter_leaves().map(|(b, _)| b.len()).sum::<usize>(),
+        boundary.len()
+    );
+    let mut array = Array2d::new(boundary);
+    for (region, value) in tree.iter_leaves() {
+        array.slice_mut(region).fill(*value);
+    }
+    assert_eq!(array, tree.cloned());
+    assert_eq!(array[[-10, -5]], 1);
+    assert_eq!(array[[50, 50]], 2);
+    assert_eq!(QuadTree2d::from(&array), tree);
+
+    // Filling the whole boundary collapses the tree, ignoring padding.
+    tree.fill(Boundary::min_dim([-20, -20], [200, 200]), 3);
+    assert_eq!(tree.leaf_count(), 1);
+    tree.set([50, 50], 4);
+    tree.set([50, 50], 3);
+    assert_eq!(tree.leaf_count(), 1);
+
+    let array = Array2d::init(boundary, |[x, y]: [i32; 2]| (x >= 22 && y >= 27) as i32);
+    let tree = QuadTree2d::from(&array);
+    assert_eq!(tree.leaf_count(), 8);
+    assert_eq!(Array2d::from(&tree), array);
+
+    // Padding outside of the boundary is ignored when comparing.
+    let array = Array2d::init([0, 0]..=[2, 2], |[x, y]: [i32; 2]| x + y * 3);
+    let built = QuadTree2d::from(&array);
+    let mut set = QuadTree2d::new([0, 0]..=[2, 2]);
+    for ([x, y], value) in array.iter::<[i32; 2]>() {
+        set.set([x, y], *value);
+    }
+    assert_eq!(built.cloned(), set.cloned());
+    assert_eq!(built, set);
+    set.set([2, 2], 0);
+    assert_ne!(built, set);
+    assert_ne!(built, QuadTree2d::new_filled([0, 0]..=[2, 3], 0));
+}
+
+/// A non-contiguous storage split into chunks of 4.
+struct Chunked<T>(Vec<Vec<T>>);
+
+impl<T> traits::Array2dStorage for Chunked<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|chunk| chunk.len()).sum()
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        traits::Array2dStorageRef::get(self, index).cloned()
+    }
+}
+
+impl<T> traits::Array2dStorageRef for Chunked<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.0.get(index / 4)?.get(index % 4)
+    }
+}
+
+impl<T> traits::Array2dStorageMut for Chunked<T> {
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index / 4)?.get_mut(index % 4)
+    }
+
+    fn iter_strided_mut(
+        &mut self,
+        start: usize,
+        width: usize,
+        pitch: usize,
+        height: usize,
+    ) -> impl Iterator<Item = &mut T> {
+        let pitch = pitch.max(1);
+        self.0
+            .iter_mut()
+            .flatten()
+            .skip(start)
+            .enumerate()
+            .filter_map(move |(i, v)| (i / pitch < height && i % pitch < width).then_some(v))
+    }
+}
+
+impl<T> traits::Array2dStorageOwned for Chunked<T> {
+    fn from_vec(vec: Vec<T>) -> Self {
+        let mut iter = vec.into_iter().peekable();
+        let mut chunks = Vec::new();
+        while iter.peek().is_some() {
+            chunks.push(iter.by_ref().take(4).collect());
+        }
+        Chunked(chunks)
+    }
+
+    fn into_owned_iter(self) -> impl Iterator<Item = T> {
+        self.0.into_iter().flatten()
+    }
+}
+
+#[test]
+pub fn custom_storage() {
+    let mut array = GenericArray2d::<Chunked<i32>>::init([3, 3], |[x, y]: [i32; 2]| x + y * 3);
+    assert_eq!(array.get([2, 1]), Some(&5));
+    assert_eq!(array.get([3, 1]), None);
+    assert_eq!(array[[1, 2]], 7);
+    assert!(array.set([0, 0], -1));
+    iter_eq(array.values().copied(), [-1, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(array.fold_rows(0, |a, b| a + b), vec![2, 12, 21]);
+    assert_eq!(array.column_sums::<i32>(), vec![8, 12, 15]);
+
+    let brush = Array2d::new_filled([2, 2], 10);
+    array.paint(&brush, [1, 1], |a, b| *a += b);
+    assert_eq!(
+        array,
+        Array2d::from_vec(vec![-1, 1, 2, 3, 14, 15, 6, 17, 18], [3, 3])
+    );
+
+    let mut target = Array2d::<i32>::new([2, 2]);
+    target.paint(&array, [-1, -1], |a, b| *a = *b);
+    iter_eq(target.values().copied(), [14, 15, 17, 18]);
+
+    array.resize(Boundary::min_max([1, 1], [3, 2]));
+    iter_eq(array.values().copied(), [14, 15, 0, 17, 18, 0]);
+
+    array.insert([-1, 0], 9);
+    assert_eq!(array.boundary(), Boundary::min_max([-1, 0], [3, 2]));
+    assert_eq!(array.get([-1, 0]), Some(&9));
+    assert_eq!(array.get([2, 2]), Some(&18));
+    assert_eq!(array.values().filter(|v| **v != 0).count(), 5);
+
+    array.shrink_to_fit();
+    assert_eq!(array.capacity(), array.boundary());
+    assert_eq!(
+        array.iter::<[i32; 2]>().find(|(_, v)| **v == 15).unwrap().0,
+        [2, 1]
+    );
+
+    let doubled = &array + &array;
+    assert_eq!(doubled.get([2, 2]), Some(&36));
+    let mut sum = 0;
+    array.zip(&doubled).for_each(|a, b| sum += a + b);
+    assert_eq!(sum, 3 * (9 + 14 + 15 + 17 + 18));
+
+    // Owned operators only require mutable access to the items.
+    let mut chunked = GenericArray2d::<Chunked<i32>>::init([3, 3], |[x, y]: [i32; 2]| x + y * 3);
+    chunked += &Array2d::new_filled([3, 3], 1);
+    let chunked = chunked - &Array2d::new_filled([3, 3], 2);
+    iter_eq(chunked.values().copied(), -1..8);
+}
+
+/// A bit-packed storage that cannot return references to its items.
+struct Bits {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl traits::Array2dStorage for Bits {
+    type Item = bool;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get_cloned(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| self.words[index / 64] & (1 << (index % 64)) != 0)
+    }
+}
+
+impl traits::Array2dStorageSet for Bits {
+    fn set(&mut self, index: usize, value: bool) -> bool {
+        if index >= self.len {
+            return false;
+        }
+        if value {
+            self.words[index / 64] |= 1 << (index % 64);
+        } else {
+            self.words[index / 64] &= !(1 << (index % 64));
+        }
+        true
+    }
+}
+
+#[test]
+pub fn bit_packed_storage() {
+    let bits = Bits {
+        words: vec![0; 2],
+        len: 100,
+    };
+    let mut array =
+        GenericArray2d::from_storage_pitch(bits, Boundary::min_max([1, 1], [8, 10]), 10);
+    assert_eq!(array.len(), 80);
+    assert!(array.set([1, 1], true));
+    assert!(array.set([8, 10], true));
+    assert!(array.set([4, 5], true));
+    assert!(!array.set([9, 1], true));
+    assert_eq!(array.get_cloned([4, 5]), Some(true));
+    assert_eq!(array.get_cloned([4, 6]), Some(false));
+    assert_eq!(array.get_cloned([0, 0]), None);
+    assert!(array.fetch([8, 10]));
+    assert_eq!(array.values_cloned().filter(|v| *v).count(), 3);
+    iter_eq(
+        array
+            .iter_cloned::<[i32; 2]>()
+            .filter_map(|(p, v)| v.then_some(p)),
+        [[1, 1], [4, 5], [8, 10]],
+    );
+
+    array.displace([-1, -1]);
+    assert!(array.fetch([0, 0]));
+    let storage = array.into_storage();
+    assert_eq!(storage.words[0], 1 | 1 << 43);
+    assert!(GenericArray2d::<Bits>::try_from_storage_pitch(storage, [10, 11], 10).is_err());
+}
+
+#[test]
+pub fn generic_coordinate() {
+    let far = 1i64 << 40;
+    let boundary = GenericBoundary::<i64>::min_dim([far, -far], [3, 2]);
+    let mut array = GenericArray2d::<Vec<i64>, i64>::init(boundary, |p: [i64; 2]| p[0] - far);
+    iter_eq(array.values().copied(), [0, 1, 2, 0, 1, 2]);
+    assert_eq!(array.get([far + 2, 1 - far]), Some(&2));
+    assert_eq!(array.get([2i64, 1]), None);
+
+    array.insert([far - 1, -far], 9);
+    assert_eq!(
+        array.boundary(),
+        GenericBoundary::min_max([far - 1, -far], [far + 2, 1 - far])
+    );
+
+    let small = GenericArray2d::<Vec<u8>, i16>::new_filled(
+        GenericBoundary::<i16>::min_max([-2, -2], [1, 1]),
+        1,
+    );
+    assert_eq!(small.len(), 16);
+    assert_eq!((&small + &small).get([1i16, 1]), Some(&2));
+    assert_eq!(std::mem::size_of::<GenericBoundary<i16>>(), 8);
+}
+
+#[test]
+pub fn narrow_coordinate() {
+    // Wider than `i8::MAX`, local offsets do not fit in the coordinate type.
+    let boundary = GenericBoundary::<i8>::min_max([-100, 0], [99, 1]);
+    assert_eq!(boundary.dimension, [200u8, 2].into());
+    let mut array = GenericArray2d::<Vec<i16>, i8>::init(boundary, |p: [i8; 2]| p[0] as i16);
+    assert_eq!(array.get([99i8, 1]), Some(&99));
+    assert_eq!(array[[-100i8, 1]], -100);
+    assert_eq!(unsafe { *array.get_unchecked([90i8, 0]) }, 90);
+    assert_eq!(array.iter::<[i8; 2]>().last(), Some(([99, 1], &99)));
+    iter_eq(array.slice([90i8, 0]..=[99, 0]).values().copied(), 90..=99);
+    iter_eq(
+        array
+            .clone()
+            .iter_owned::<[i8; 2]>()
+            .map(|(p, _)| p)
+            .skip(398),
+        [[98, 1], [99, 1]],
+    );
+
+    array.copy_within([-100i8, 0]..=[-91, 0], [90i8, 1]);
+    iter_eq(
+        array.slice([90i8, 1]..=[99, 1]).values().copied(),
+        -100..=-91,
+    );
+
+    let mut brush = GenericArray2d::<Vec<i16>, i8>::new_filled([0i8, 0]..=[9, 0], 7);
+    brush.displace([90i8, 0]);
+    array.paint(&brush, [0i8, 0], |a, b| *a = *b);
+    assert_eq!(array.get([95i8, 0]), Some(&7));
+
+    // Growing past `i8::MAX` in local space keeps existing values in place.
+    let mut array =
+        GenericArray2d::<Vec<i16>, i8>::init([-100i8, 0]..=[0, 0], |p: [i8; 2]| p[0] as i16);
+    array.insert([120i8, 1], 1);
+    assert_eq!(
+        array.boundary(),
+        GenericBoundary::min_max([-100, 0], [120, 1])
+    );
+    assert_eq!(array.get([-100i8, 0]), Some(&-100));
+    assert_eq!(array.get([0i8, 0]), Some(&0));
+    assert_eq!(array.get([120i8, 1]), Some(&1));
+    assert_eq!(array.get([60i8, 0]), Some(&0));
+    let capacity = array.capacity();
+    assert_eq!(capacity.union(array.boundary()), capacity);
+    array.resize([-110i8, -1]..=[110, 1]);
+    assert_eq!(array.get([-100i8, 0]), Some(&-100));
+    assert_eq!(array.get([120i8, 1]), None);
+
+    // A pitch wider than the coordinate range is clamped in `capacity`.
+    let array = GenericArray2d::<Vec<u8>, i8>::from_vec_pitch(vec![0; 600], [0i8, 0]..=[1, 1], 300);
+    assert_eq!(array.capacity(), GenericBoundary::min_max([0, 0], [127, 1]));
+}
+
+#[test]
+pub fn fallible() {
+    assert_eq!(
+        Array2d::try_from_vec(vec![1, 2, 3], [2, 2]),
+        Err(Array2dError::NotEnoughItems { needed: 4, got: 3 })
+    );
+    assert_eq!(
+        Array2d::try_from_vec_pitch(vec![1, 2, 3, 4], [2, 2], 1),
+        Err(Array2dError::PitchTooSmall { pitch: 1, width: 2 })
+    );
+    assert_eq!(
+        Array2d::<i32>::try_from_vec_pitch(Vec::new(), [1, 2], usize::MAX),
+        Err(Array2dError::DimensionOverflow)
+    );
+    let array = Array2d::try_from_vec(vec![1, 2, 3, 4], [2, 2]).unwrap();
+    assert_eq!(array.get([1, 1]), Some(&4));
+
+    // Dimensions are not truncated to `usize` on narrow targets.
+    let huge = 1i64 << 40;
+    assert_eq!(
+        GenericArray2d::<Vec<i32>, i64>::try_from_vec(
+            Vec::new(),
+            GenericBoundary::<i64>::min_dim([0, 0], [huge, huge])
+        ),
+        Err(Array2dError::DimensionOverflow)
+    );
+    let wide = GenericArray2d::<Vec<i32>, i64>::try_from_vec(
+        Vec::new(),
+        GenericBoundary::<i64>::min_dim([0, 0], [huge, 1]),
+    );
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(
+        wide,
+        Err(Array2dError::NotEnoughItems {
+            needed: huge as usize,
+            got: 0
+        })
+    );
+    #[cfg(not(target_pointer_width = "64"))]
+    assert_eq!(wide, Err(Array2dError::DimensionOverflow));
+
+    let slice = [1, 2, 3, 4, 5, 6];
+    let array = garray2d::Array2dRef::try_from_slice_pitch(&slice, [2, 2], 3).unwrap();
+    iter_eq(array.values().copied(), [1, 2, 4, 5]);
+    assert!(garray2d::Array2dRef::try_from_slice(&slice, [3, 3]).is_err());
+
+    let mut slice = [0; 4];
+    assert!(garray2d::Array2dMut::try_from_slice(&mut slice, [2, 2]).is_ok());
+    assert_eq!(
+        garray2d::Array2dMut::try_from_slice_pitch(&mut slice, [2, 2], 1).unwrap_err(),
+        Array2dError::PitchTooSmall { pitch: 1, width: 2 }
+    );
+}
+
+#[test]
+pub fn boundary_overflow() {
+    use traits::IntoBoundary;
+    const MAX: i32 = i32::MAX;
+    const MIN: i32 = i32::MIN;
+
+    let all = Boundary::ALL;
+    assert_eq!(all.len_u64(), u32::MAX as u64 * u32::MAX as u64);
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(
+        all.checked_len(),
+        Some(u32::MAX as usize * u32::MAX as usize)
+    );
+    #[cfg(not(target_pointer_width = "64"))]
+    assert_eq!(all.checked_len(), None);
+    assert_eq!(all.checked_max_non_inclusive(), Some([MAX, MAX].into()));
+    assert_eq!(all.checked_expand_by([1, 1]), None);
+    assert_eq!(all.saturating_expand_by([1, 1]), all);
+    assert_eq!(all.checked_intersection(all), Some(all));
+
+    let far: Boundary = ([-5, -5]..).into_boundary();
+    assert_eq!(far.len_u64(), (MAX as u64 + 5) * (MAX as u64 + 5));
+    assert_eq!(
+        far.checked_displace_by([1, 0]),
+        Some(Boundary::min_max([-4, -5], [MAX, MAX - 1]))
+    );
+    assert_eq!(far.checked_displace_by([2, 0]), None);
+    assert_eq!(
+        far.saturating_displace_by([2, 0]),
+        Boundary::min_max([-3, -5], [MAX, MAX - 1])
+    );
+    assert_eq!(far.intersection(all), Some(far));
+
+    let edge = Boundary::min_dim([MAX - 2, 0], [4, 1]);
+    assert_eq!(edge.checked_max_non_inclusive(), None);
+    assert_eq!(edge.saturating_max_non_inclusive(), [MAX, 1].into());
+    assert_eq!(edge.checked_intersection(all), None);
+    assert_eq!(
+        edge.intersection(all),
+        Some(Boundary::min_max([MAX - 2, 0], [MAX, 0]))
+    );
+
+    let corner = Boundary::from_point([MIN, MIN]);
+    assert_eq!(corner.checked_displace_by([-1, 0]), None);
+    assert_eq!(corner.saturating_displace_by([-1, 0]).len(), 0);
+    assert_eq!(
+        corner.checked_displace_by([1, 0]).unwrap().min,
+        [MIN + 1, MIN].into()
+    );
+
+    // `expand` moves `min` and keeps `max`.
+    let expanded = Boundary::min_max([-2, -1], [0, 0]);
+    assert_eq!(Boundary::from_point([0, 0]).expand_by([2, 1]), expanded);
+    assert_eq!(
+        Boundary::from_point([0, 0]).checked_expand_by([2, 1]),
+        Some(expanded)
+    );
+    assert_eq!(
+        Boundary::from_point([0, 0]).saturating_expand_by([2, 1]),
+        expanded
+    );
+    assert_eq!(
+        Boundary::from_point([MIN, 0]).saturating_expand_by([2, 1]),
+        Boundary::min_max([MIN, -1], [MIN, 0])
+    );
+    assert_eq!(
+        Boundary::from_point([0, 0]).saturating_expand_by([-2, 0]),
+        Boundary::min_dim([2, 0], [0, 1])
+    );
+
+    for min in [[MIN, MIN], [-1, -1], [MAX - 2, MAX - 2]] {
+        let array = Array2d::init(Boundary::min_dim(min, [3, 3]), |v: [i32; 2]| v[0]);
+        let at_max = min[0] == MAX - 2;
+        assert_eq!(array.slice(..), array);
+        assert_eq!(array.slice(Boundary::ALL), array);
+        assert_eq!(
+            array.slice([-5, -5]..).len(),
+            match min[0] {
+                MIN => 0,
+                // `[-5, -5]..` ends at `MAX - 1`.
+                _ if at_max => 4,
+                _ => 9,
+            }
+        );
+        assert!(array.get(Boundary::ALL).is_none());
+        assert!(array.get(..).is_none());
+        assert_eq!(array.get([MIN, MAX]), None);
+        assert_eq!(array.get([MAX, MIN]), None);
+        assert_eq!(array.get([MAX, MAX]).is_some(), at_max);
+        assert_eq!(array.get([min[0] + 2, min[1] + 2]), Some(&(min[0] + 2)));
+    }
+
+    // Boundaries may end at `MAX`.
+    let boundary = Boundary::min_dim([MAX - 1, MAX - 1], [2, 2]);
+    assert!(boundary.contains([MAX, MAX]));
+    assert!(!boundary.contains([MAX - 2, MAX]));
+    assert_eq!(boundary.checked_max_non_inclusive(), None);
+    assert_eq!(Boundary::ALL.intersection(boundary), Some(boundary));
+    assert_eq!(boundary.union(boundary), boundary);
+    let array = Array2d::init(boundary, |v: [i32; 2]| v[0]);
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.slice(..).len(), 4);
+    assert_eq!(array.slice(Boundary::ALL).len(), 4);
+    assert_eq!(array.get([MAX, MAX]), Some(&MAX));
+    let mut mask = Mask2d::new(boundary);
+    assert!(mask.set([MAX, MAX], true));
+    assert!(mask.get([MAX, MAX]));
+    let mut rle = RleArray2d::new(boundary);
+    assert!(rle.set([MAX, MAX], 1));
+    assert_eq!(rle.get([MAX, MAX]), Some(&1));
+    let mut quad = QuadTree2d::new(boundary);
+    assert!(quad.set([MAX, MAX], 1));
+    assert_eq!(quad.get([MAX, MAX]), Some(&1));
+    assert_eq!(quad.get([MAX - 1, MAX]), Some(&0));
+    let mut tiled = TiledArray2d::new(boundary);
+    assert!(tiled.set([MAX, MAX], 1));
+    assert_eq!(tiled.get([MAX, MAX]), Some(&1));
+    assert_eq!(tiled.iter::<[i32; 2]>().last(), Some(([MAX, MAX], &1)));
+    let mut scrolling = ScrollingArray2d::init(
+        Boundary::min_dim([MAX - 3, MAX - 3], [2, 2]),
+        |v: [i32; 2]| v[0],
+    );
+    scrolling.recenter_with(boundary, |v: [i32; 2]| v[0]);
+    assert_eq!(scrolling.get([MAX, MAX]), Some(&MAX));
+    let mut grow = Array2d::new_filled(Boundary::min_dim([MAX - 3, MAX - 3], [1, 1]), 0);
+    grow.insert([MAX, MAX], 1);
+    assert_eq!(
+        grow.boundary(),
+        Boundary::min_max([MAX - 3, MAX - 3], [MAX, MAX])
+    );
+    assert_eq!(grow.get([MAX, MAX]), Some(&1));
+    assert_eq!(grow.get([MAX - 1, MAX]), Some(&0));
+}
+
+#[test]
+pub fn boundary3_overflow() {
+    const MAX: i32 = i32::MAX;
+
+    let huge = Boundary3::min_dim([0, 0, 0], [u32::MAX; 3]);
+    assert_eq!(huge.len(), usize::MAX);
+    assert_eq!(huge.checked_len(), None);
+    assert_eq!(huge.checked_max_non_inclusive(), None);
+    assert_eq!(huge.saturating_max_non_inclusive(), [MAX; 3].into());
+    assert_eq!(huge.checked_intersection(Boundary3::ALL), None);
+    assert_eq!(
+        huge.intersection(Boundary3::ALL),
+        Some(Boundary3::min_max([0, 0, 0], [MAX; 3]))
+    );
+    assert_eq!(
+        Boundary3::ALL.checked_intersection(Boundary3::ALL),
+        Some(Boundary3::ALL)
+    );
+    assert_eq!(Boundary3::ALL.union(huge), Boundary3::ALL);
+
+    // Boundaries may end at `MAX`.
+    let boundary = Boundary3::min_dim([MAX - 1; 3], [2; 3]);
+    assert!(boundary.contains([MAX; 3]));
+    assert_eq!(boundary.max(), [MAX; 3].into());
+    assert_eq!(
+        boundary.checked_intersection(Boundary3::ALL),
+        Some(boundary)
+    );
+    assert_eq!(boundary.union(boundary), boundary);
+    let array = Array3d::init(boundary, |v: [i32; 3]| v[0]);
+    assert_eq!(array.slice(..).len(), 8);
+    assert_eq!(array.get([MAX; 3]), Some(&MAX));
+}
+
+#[test]
+pub fn slice_empty() {
+    let mut arr = Array2d::init([5, 5]..=[7, 7], |v: IVec2| v.x);
+    // Empty boundaries at the far corner have an offset past the end of the buffer.
+    assert!(arr.slice(Boundary::min_dim([8, 8], [0, 0])).is_empty());
+    assert!(arr.slice_mut(Boundary::min_dim([8, 8], [0, 0])).is_empty());
+    assert!(arr.slice(Boundary::EMPTY).is_empty());
+
+    // An empty boundary has no points outside of the array.
+    assert_eq!(
+        arr.get(Boundary::min_dim([8, 8], [0, 0])).map(|s| s.len()),
+        Some(0)
+    );
+    assert_eq!(
+        arr.get(Boundary::min_dim([0, 6], [9, 0])).map(|s| s.len()),
+        Some(0)
+    );
+    assert!(arr.get_mut(Boundary::EMPTY).is_some());
+    assert!(arr.get(Boundary::min_max([6, 6], [8, 8])).is_none());
+}